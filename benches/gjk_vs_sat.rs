@@ -0,0 +1,52 @@
+use std::{f64::consts::TAU, hint::black_box, time::Instant};
+
+use collideoscope::{
+    narrow::{gjk::gjk_collides, sat::SATable, shapes::polygon::Polygon},
+    vec2::Vec2,
+};
+
+static ITERATIONS: u32 = 1_000;
+
+fn regular_polygon(sides: usize, radius: f64) -> Polygon<f64> {
+    Polygon::new(
+        (0..sides)
+            .map(|i| {
+                let angle = TAU * i as f64 / sides as f64;
+                Vec2::new(radius * angle.cos(), radius * angle.sin())
+            })
+            .collect(),
+    )
+    .unwrap()
+}
+
+fn bench(name: &str, mut f: impl FnMut() -> bool) {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<32} {:>10.2?} total {:>10.2?} / iter",
+        elapsed,
+        elapsed / ITERATIONS
+    );
+}
+
+fn main() {
+    for sides in [8, 32, 128, 512] {
+        let poly = regular_polygon(sides, 1.0);
+
+        for (label, offset) in [
+            ("overlapping", Vec2::new(1.5, 0.3)),
+            ("separated", Vec2::new(2.5, 0.3)),
+        ] {
+            bench(&format!("sat {sides} sides, {label}"), || {
+                poly.sat_collides(Vec2::zero(), black_box(&poly), offset)
+            });
+            bench(&format!("gjk {sides} sides, {label}"), || {
+                gjk_collides(&poly, Vec2::zero(), black_box(&poly), offset)
+            });
+        }
+    }
+}
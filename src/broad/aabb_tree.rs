@@ -0,0 +1,477 @@
+use crate::{broad::bounding_box::BoundingBox, NumTolerance};
+
+struct Node<T: NumTolerance> {
+    bounds: BoundingBox<T>,
+    parent: Option<usize>,
+    children: Option<[usize; 2]>,
+    height: usize,
+}
+
+impl<T: NumTolerance> Node<T> {
+    fn is_leaf(&self) -> bool {
+        self.children.is_none()
+    }
+}
+
+pub struct AABBTree<T: NumTolerance> {
+    nodes: Vec<Node<T>>,
+    free: Vec<usize>,
+    root: Option<usize>,
+    unbounded: Vec<usize>,
+    margin: T,
+    len: usize,
+}
+
+impl<T: NumTolerance> AABBTree<T> {
+    pub fn new(margin: T) -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            unbounded: Vec::new(),
+            margin,
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn fat_bounds(&self, proxy: usize) -> &BoundingBox<T> {
+        &self.nodes[proxy].bounds
+    }
+
+    // Boxes too large for the perimeter heuristic, such as a half-plane's, are kept beside the tree and checked by every query
+    pub fn insert(&mut self, bounds: BoundingBox<T>) -> usize {
+        let leaf = self.allocate(bounds.fattened(self.margin));
+        self.link(leaf);
+        self.len += 1;
+
+        leaf
+    }
+
+    pub fn remove(&mut self, proxy: usize) {
+        debug_assert!(self.nodes[proxy].is_leaf());
+
+        self.unlink(proxy);
+        self.release(proxy);
+        self.len -= 1;
+    }
+
+    // Returns true if the proxy was reinserted, which only happens once the shape leaves its fattened bounds
+    pub fn move_proxy(&mut self, proxy: usize, bounds: BoundingBox<T>) -> bool {
+        debug_assert!(self.nodes[proxy].is_leaf());
+
+        if self.nodes[proxy].bounds.contains(&bounds) {
+            return false;
+        }
+
+        self.unlink(proxy);
+        self.nodes[proxy].bounds = bounds.fattened(self.margin);
+        self.link(proxy);
+
+        true
+    }
+
+    pub fn query(&self, bounds: &BoundingBox<T>) -> Vec<usize> {
+        let mut found = self.query_tree(bounds);
+        found.extend(
+            self.unbounded
+                .iter()
+                .filter(|&&proxy| self.nodes[proxy].bounds.overlaps(bounds)),
+        );
+
+        found
+    }
+
+    pub fn pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+
+        while let Some(index) = stack.pop() {
+            match self.nodes[index].children {
+                Some(children) => stack.extend(children),
+                None => {
+                    for other in self.query_tree(&self.nodes[index].bounds) {
+                        if index < other {
+                            pairs.push((index, other));
+                        }
+                    }
+                }
+            }
+        }
+
+        // Two unbounded proxies are paired once, from the lower one
+        for &proxy in &self.unbounded {
+            for other in self.query(&self.nodes[proxy].bounds) {
+                if other != proxy && !(other < proxy && self.unbounded.contains(&other)) {
+                    pairs.push((usize::min(proxy, other), usize::max(proxy, other)));
+                }
+            }
+        }
+
+        pairs
+    }
+
+    fn query_tree(&self, bounds: &BoundingBox<T>) -> Vec<usize> {
+        let mut found = Vec::new();
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !node.bounds.overlaps(bounds) {
+                continue;
+            }
+
+            match node.children {
+                Some(children) => stack.extend(children),
+                None => found.push(index),
+            }
+        }
+
+        found
+    }
+
+    fn link(&mut self, leaf: usize) {
+        match self.nodes[leaf].bounds.perimeter().is_finite() {
+            true => self.insert_leaf(leaf),
+            false => self.unbounded.push(leaf),
+        }
+    }
+
+    fn unlink(&mut self, leaf: usize) {
+        match self.unbounded.iter().position(|&proxy| proxy == leaf) {
+            Some(position) => {
+                self.unbounded.swap_remove(position);
+            }
+            None => self.remove_leaf(leaf),
+        }
+    }
+
+    fn allocate(&mut self, bounds: BoundingBox<T>) -> usize {
+        let node = Node {
+            bounds,
+            parent: None,
+            children: None,
+            height: 0,
+        };
+
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn release(&mut self, index: usize) {
+        self.nodes[index].parent = None;
+        self.nodes[index].children = None;
+        self.free.push(index);
+    }
+
+    fn replace_child(&mut self, parent: Option<usize>, old: usize, new: usize) {
+        match parent {
+            Some(parent) => {
+                if let Some(children) = self.nodes[parent].children.as_mut() {
+                    match children[0] == old {
+                        true => children[0] = new,
+                        false => children[1] = new,
+                    }
+                }
+            }
+            None => self.root = Some(new),
+        }
+    }
+
+    fn insert_leaf(&mut self, leaf: usize) {
+        let mut index = match self.root {
+            Some(root) => root,
+            None => {
+                self.root = Some(leaf);
+                self.nodes[leaf].parent = None;
+                return;
+            }
+        };
+
+        let two = T::one() + T::one();
+        let leaf_bounds = self.nodes[leaf].bounds;
+
+        // Descend towards the sibling that grows the total perimeter of the tree the least
+        while let Some(children) = self.nodes[index].children {
+            let perimeter = self.nodes[index].bounds.perimeter();
+            let combined = self.nodes[index].bounds.merged(&leaf_bounds).perimeter();
+
+            let cost = two * combined;
+            let inheritance = two * (combined - perimeter);
+
+            let child_cost = |child: usize| {
+                let node = &self.nodes[child];
+                let merged = node.bounds.merged(&leaf_bounds).perimeter();
+
+                match node.is_leaf() {
+                    true => merged + inheritance,
+                    false => merged - node.bounds.perimeter() + inheritance,
+                }
+            };
+
+            let cost0 = child_cost(children[0]);
+            let cost1 = child_cost(children[1]);
+
+            if cost < cost0 && cost < cost1 {
+                break;
+            }
+
+            index = match cost0 < cost1 {
+                true => children[0],
+                false => children[1],
+            };
+        }
+
+        let sibling = index;
+        let old_parent = self.nodes[sibling].parent;
+        let new_parent = self.allocate(self.nodes[sibling].bounds.merged(&leaf_bounds));
+
+        self.nodes[new_parent].parent = old_parent;
+        self.nodes[new_parent].children = Some([sibling, leaf]);
+        self.nodes[new_parent].height = self.nodes[sibling].height + 1;
+        self.replace_child(old_parent, sibling, new_parent);
+
+        self.nodes[sibling].parent = Some(new_parent);
+        self.nodes[leaf].parent = Some(new_parent);
+
+        self.refit(self.nodes[leaf].parent);
+    }
+
+    fn remove_leaf(&mut self, leaf: usize) {
+        if self.root == Some(leaf) {
+            self.root = None;
+            return;
+        }
+
+        let parent = self.nodes[leaf].parent.unwrap();
+        let grand_parent = self.nodes[parent].parent;
+        let children = self.nodes[parent].children.unwrap();
+        let sibling = match children[0] == leaf {
+            true => children[1],
+            false => children[0],
+        };
+
+        self.replace_child(grand_parent, parent, sibling);
+        self.nodes[sibling].parent = grand_parent;
+        self.nodes[leaf].parent = None;
+        self.release(parent);
+
+        self.refit(grand_parent);
+    }
+
+    fn refit(&mut self, start: Option<usize>) {
+        let mut current = start;
+
+        while let Some(index) = current {
+            let index = self.balance(index);
+            let [first, second] = self.nodes[index].children.unwrap();
+
+            self.nodes[index].height =
+                1 + usize::max(self.nodes[first].height, self.nodes[second].height);
+            self.nodes[index].bounds = self.nodes[first].bounds.merged(&self.nodes[second].bounds);
+
+            current = self.nodes[index].parent;
+        }
+    }
+
+    // Performs a single rotation if one child is more than one level taller than the other, returning the new subtree root
+    fn balance(&mut self, a: usize) -> usize {
+        let [b, c] = match self.nodes[a].children {
+            Some(children) if self.nodes[a].height >= 2 => children,
+            _ => return a,
+        };
+
+        let balance = self.nodes[c].height as isize - self.nodes[b].height as isize;
+
+        if balance > 1 {
+            self.rotate_up(a, c, b, 1)
+        } else if balance < -1 {
+            self.rotate_up(a, b, c, 0)
+        } else {
+            a
+        }
+    }
+
+    // Moves `high` into the place of `a`, handing its shorter child to `a` which keeps `low` in slot `1 - slot`
+    fn rotate_up(&mut self, a: usize, high: usize, low: usize, slot: usize) -> usize {
+        let [f, g] = self.nodes[high].children.unwrap();
+        let (taller, shorter) = match self.nodes[f].height > self.nodes[g].height {
+            true => (f, g),
+            false => (g, f),
+        };
+
+        let a_parent = self.nodes[a].parent;
+        self.nodes[high].parent = a_parent;
+        self.replace_child(a_parent, a, high);
+        self.nodes[a].parent = Some(high);
+        self.nodes[high].children = Some([a, taller]);
+
+        let mut a_children = [low, low];
+        a_children[slot] = shorter;
+        self.nodes[a].children = Some(a_children);
+        self.nodes[shorter].parent = Some(a);
+
+        self.nodes[a].bounds = self.nodes[low].bounds.merged(&self.nodes[shorter].bounds);
+        self.nodes[a].height = 1 + usize::max(self.nodes[low].height, self.nodes[shorter].height);
+
+        self.nodes[high].bounds = self.nodes[a].bounds.merged(&self.nodes[taller].bounds);
+        self.nodes[high].height = 1 + usize::max(self.nodes[a].height, self.nodes[taller].height);
+
+        high
+    }
+}
+
+#[cfg(test)]
+mod aabb_tree_tests {
+
+    use super::AABBTree;
+    use crate::{broad::bounding_box::BoundingBox, vec2::Vec2};
+
+    fn grid_boxes() -> Vec<BoundingBox<f64>> {
+        let mut boxes = Vec::new();
+
+        for i in 0..12 {
+            for j in 0..12 {
+                let min = Vec2::new(i as f64 * 1.5, j as f64 * 1.25);
+                boxes.push(BoundingBox::new(min, min + Vec2::new(2.0, 1.0)));
+            }
+        }
+
+        boxes
+    }
+
+    fn brute_force_pairs(tree: &AABBTree<f64>, proxies: &[usize]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+
+        for (i, first) in proxies.iter().enumerate() {
+            for second in proxies.iter().skip(i + 1) {
+                if tree.fat_bounds(*first).overlaps(tree.fat_bounds(*second)) {
+                    pairs.push((usize::min(*first, *second), usize::max(*first, *second)));
+                }
+            }
+        }
+
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn test_pairs() {
+        let mut tree = AABBTree::new(0.1);
+        let proxies: Vec<usize> = grid_boxes().into_iter().map(|b| tree.insert(b)).collect();
+
+        let mut pairs = tree.pairs();
+        pairs.sort();
+
+        assert_eq!(tree.len(), 144);
+        assert_eq!(pairs, brute_force_pairs(&tree, &proxies));
+    }
+
+    #[test]
+    fn test_balanced() {
+        let mut tree = AABBTree::new(0.0);
+
+        for i in 0..256 {
+            let min = Vec2::new(i as f64, 0.0);
+            tree.insert(BoundingBox::new(min, min + Vec2::new(0.5, 0.5)));
+        }
+
+        assert!(tree.nodes[tree.root.unwrap()].height <= 16);
+    }
+
+    #[test]
+    fn test_query() {
+        let mut tree = AABBTree::new(0.0);
+        let near = tree.insert(BoundingBox::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)));
+        let far = tree.insert(BoundingBox::new(
+            Vec2::new(10.0, 10.0),
+            Vec2::new(11.0, 11.0),
+        ));
+        tree.insert(BoundingBox::new(Vec2::new(-5.0, 3.0), Vec2::new(-4.0, 4.0)));
+
+        let found = tree.query(&BoundingBox::new(
+            Vec2::new(0.5, 0.5),
+            Vec2::new(10.5, 10.5),
+        ));
+        assert_eq!(found.len(), 2);
+        assert!(found.contains(&near));
+        assert!(found.contains(&far));
+
+        assert!(tree
+            .query(&BoundingBox::new(Vec2::new(3.0, 3.0), Vec2::new(4.0, 4.0)))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_move_and_remove() {
+        let mut tree = AABBTree::new(0.5);
+        let mut proxies: Vec<usize> = grid_boxes().into_iter().map(|b| tree.insert(b)).collect();
+
+        let small = BoundingBox::new(Vec2::new(0.25, 0.0), Vec2::new(2.25, 1.0));
+        assert!(!tree.move_proxy(proxies[0], small));
+
+        let large = BoundingBox::new(Vec2::new(30.0, 30.0), Vec2::new(32.0, 31.0));
+        assert!(tree.move_proxy(proxies[1], large));
+        assert!(tree.fat_bounds(proxies[1]).contains(&large));
+
+        for proxy in proxies.drain(20..60) {
+            tree.remove(proxy);
+        }
+
+        let mut pairs = tree.pairs();
+        pairs.sort();
+
+        assert_eq!(tree.len(), 104);
+        assert_eq!(pairs, brute_force_pairs(&tree, &proxies));
+
+        let reused = tree.insert(BoundingBox::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)));
+        proxies.push(reused);
+
+        let mut pairs = tree.pairs();
+        pairs.sort();
+        assert_eq!(pairs, brute_force_pairs(&tree, &proxies));
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let mut tree = AABBTree::new(0.1);
+        let mut proxies: Vec<usize> = grid_boxes().into_iter().map(|b| tree.insert(b)).collect();
+
+        let floor = BoundingBox::new(
+            Vec2::new(f64::NEG_INFINITY, f64::NEG_INFINITY),
+            Vec2::new(f64::INFINITY, 0.5),
+        );
+        let huge = BoundingBox::new(Vec2::new(f64::MIN, f64::MIN), Vec2::new(f64::MAX, 2.0));
+        proxies.push(tree.insert(floor));
+        proxies.push(tree.insert(huge));
+
+        let mut pairs = tree.pairs();
+        pairs.sort();
+        assert_eq!(pairs, brute_force_pairs(&tree, &proxies));
+        assert_eq!(tree.query(&floor).len(), 12 + 2);
+
+        assert!(tree.move_proxy(proxies[0], floor));
+        tree.remove(proxies[145]);
+        proxies.remove(145);
+
+        let mut pairs = tree.pairs();
+        pairs.sort();
+        assert_eq!(tree.len(), 145);
+        assert_eq!(pairs, brute_force_pairs(&tree, &proxies));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{vec2::Vec2, NumTolerance};
+
+#[derive(Clone, Copy)]
+pub struct BoundingBox<T: NumTolerance> {
+    pub min: Vec2<T>,
+    pub max: Vec2<T>,
+}
+
+impl<T: NumTolerance> BoundingBox<T> {
+    pub fn new(min: Vec2<T>, max: Vec2<T>) -> Self {
+        Self { min, max }
+    }
+
+    pub fn overlaps(&self, rhs: &BoundingBox<T>) -> bool {
+        self.min.x < rhs.max.x
+            && rhs.min.x < self.max.x
+            && self.min.y < rhs.max.y
+            && rhs.min.y < self.max.y
+    }
+
+    pub fn contains(&self, rhs: &BoundingBox<T>) -> bool {
+        self.min.x <= rhs.min.x
+            && self.min.y <= rhs.min.y
+            && rhs.max.x <= self.max.x
+            && rhs.max.y <= self.max.y
+    }
+
+    pub fn merged(&self, rhs: &BoundingBox<T>) -> Self {
+        Self {
+            min: Vec2::new(T::min(self.min.x, rhs.min.x), T::min(self.min.y, rhs.min.y)),
+            max: Vec2::new(T::max(self.max.x, rhs.max.x), T::max(self.max.y, rhs.max.y)),
+        }
+    }
+
+    pub fn fattened(&self, margin: T) -> Self {
+        let margin = Vec2::new(margin, margin);
+
+        Self {
+            min: self.min - margin,
+            max: self.max + margin,
+        }
+    }
+
+    // A 2D box has no volume, so the perimeter stands in for surface area in the tree's cost heuristic
+    pub fn perimeter(&self) -> T {
+        let two = T::one() + T::one();
+
+        two * ((self.max.x - self.min.x) + (self.max.y - self.min.y))
+    }
+}
+
+#[cfg(test)]
+mod bounding_box_tests {
+
+    use float_eq::assert_float_eq;
+
+    use super::BoundingBox;
+    use crate::{
+        narrow::{
+            sat::SATable,
+            shapes::{aabb::AABB, capsule::Capsule, circle::Circle},
+        },
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_overlaps() {
+        let box0 = BoundingBox::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+        let box1 = BoundingBox::new(Vec2::new(1.0, 1.0), Vec2::new(3.0, 4.0));
+        let box2 = BoundingBox::new(Vec2::new(2.5, -1.0), Vec2::new(3.0, 0.5));
+
+        assert!(box0.overlaps(&box1));
+        assert!(box1.overlaps(&box0));
+        assert!(!box0.overlaps(&box2));
+        assert!(!box1.overlaps(&box2));
+    }
+
+    #[test]
+    fn test_merged() {
+        let box0 = BoundingBox::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+        let box1 = BoundingBox::new(Vec2::new(2.5, -1.0), Vec2::new(3.0, 0.5));
+
+        let merged = box0.merged(&box1);
+        assert!(merged.contains(&box0));
+        assert!(merged.contains(&box1));
+        assert!(!box0.contains(&merged));
+        assert_float_eq!(merged.perimeter(), 12.0, abs <= 0.0001);
+        assert_float_eq!(box0.fattened(0.5).perimeter(), 12.0, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_shape_bounds() {
+        let aabb = AABB::new(4.0, 2.0).bounding_box(Vec2::new(1.0, 1.0));
+        assert_float_eq!(aabb.min.x, -1.0, abs <= 0.0001);
+        assert_float_eq!(aabb.min.y, 0.0, abs <= 0.0001);
+        assert_float_eq!(aabb.max.x, 3.0, abs <= 0.0001);
+        assert_float_eq!(aabb.max.y, 2.0, abs <= 0.0001);
+
+        let circle = Circle::new(2.0).bounding_box(Vec2::new(-1.0, 0.5));
+        assert_float_eq!(circle.min.x, -3.0, abs <= 0.0001);
+        assert_float_eq!(circle.min.y, -1.5, abs <= 0.0001);
+        assert_float_eq!(circle.max.x, 1.0, abs <= 0.0001);
+        assert_float_eq!(circle.max.y, 2.5, abs <= 0.0001);
+
+        let capsule = Capsule::new(Vec2::new(1.0, 1.0), 0.5).bounding_box(Vec2::zero());
+        assert_float_eq!(capsule.min.x, -1.5, abs <= 0.0001);
+        assert_float_eq!(capsule.min.y, -1.5, abs <= 0.0001);
+        assert_float_eq!(capsule.max.x, 1.5, abs <= 0.0001);
+        assert_float_eq!(capsule.max.y, 1.5, abs <= 0.0001);
+    }
+}
@@ -1 +1,4 @@
-
+pub mod aabb_tree;
+pub mod bounding_box;
+pub mod spatial_hash;
+pub mod sweep_and_prune;
@@ -0,0 +1,409 @@
+use std::collections::HashMap;
+
+use crate::{
+    broad::bounding_box::BoundingBox,
+    narrow::shapes::{check_size, ShapeError},
+    NumTolerance,
+};
+
+// Boxes covering more cells than this are kept out of the grid and checked against everything instead
+static MAX_ENTRY_CELLS: i64 = 4096;
+
+#[derive(Clone, Copy, PartialEq)]
+struct CellRange {
+    min: (i64, i64),
+    max: (i64, i64),
+}
+
+impl CellRange {
+    fn cells(&self) -> impl Iterator<Item = (i64, i64)> {
+        let (min, max) = (self.min, self.max);
+
+        (min.0..=max.0).flat_map(move |x| (min.1..=max.1).map(move |y| (x, y)))
+    }
+
+    fn count(&self) -> Option<i64> {
+        let width = self.max.0.checked_sub(self.min.0)?.checked_add(1)?;
+        let height = self.max.1.checked_sub(self.min.1)?.checked_add(1)?;
+
+        width.checked_mul(height)
+    }
+}
+
+struct Entry<T: NumTolerance> {
+    bounds: BoundingBox<T>,
+    range: Option<CellRange>,
+}
+
+pub struct SpatialHash<T: NumTolerance> {
+    cell_size: T,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+    entries: Vec<Option<Entry<T>>>,
+    free: Vec<usize>,
+    unbounded: Vec<usize>,
+}
+
+impl<T: NumTolerance> SpatialHash<T> {
+    pub fn new(cell_size: T) -> Self {
+        Self::try_new(cell_size).expect("cell size must be positive and finite")
+    }
+
+    pub fn try_new(cell_size: T) -> Result<Self, ShapeError> {
+        check_size(cell_size)?;
+
+        Ok(Self {
+            cell_size,
+            cells: HashMap::new(),
+            entries: Vec::new(),
+            free: Vec::new(),
+            unbounded: Vec::new(),
+        })
+    }
+
+    pub fn cell_size(&self) -> T {
+        self.cell_size
+    }
+
+    // None when the point is not finite or lies beyond the range of cell indices
+    pub fn cell(&self, x: T, y: T) -> Option<(i64, i64)> {
+        Some((
+            (x / self.cell_size).floor().to_i64()?,
+            (y / self.cell_size).floor().to_i64()?,
+        ))
+    }
+
+    pub fn bounds(&self, key: usize) -> &BoundingBox<T> {
+        &self.entries[key].as_ref().unwrap().bounds
+    }
+
+    // Drops every entry so the hash can be refilled from scratch each frame
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.entries.clear();
+        self.free.clear();
+        self.unbounded.clear();
+    }
+
+    pub fn insert(&mut self, bounds: BoundingBox<T>) -> usize {
+        let range = self.range(&bounds);
+        let key = match self.free.pop() {
+            Some(key) => key,
+            None => {
+                self.entries.push(None);
+                self.entries.len() - 1
+            }
+        };
+
+        self.add_to_cells(key, range);
+        self.entries[key] = Some(Entry { bounds, range });
+
+        key
+    }
+
+    pub fn update(&mut self, key: usize, bounds: BoundingBox<T>) {
+        let range = self.range(&bounds);
+        let entry = self.entries[key].as_mut().unwrap();
+        let old_range = entry.range;
+
+        entry.bounds = bounds;
+        entry.range = range;
+
+        if old_range != range {
+            self.remove_from_cells(key, old_range);
+            self.add_to_cells(key, range);
+        }
+    }
+
+    pub fn remove(&mut self, key: usize) {
+        let entry = self.entries[key].take().unwrap();
+
+        self.remove_from_cells(key, entry.range);
+        self.free.push(key);
+    }
+
+    pub fn query(&self, bounds: &BoundingBox<T>) -> Vec<usize> {
+        let Some(range) = self.range(bounds) else {
+            return self
+                .entries
+                .iter()
+                .enumerate()
+                .filter(|(_, entry)| matches!(entry, Some(entry) if entry.bounds.overlaps(bounds)))
+                .map(|(key, _)| key)
+                .collect();
+        };
+        let mut found = Vec::new();
+
+        for cell in range.cells() {
+            for key in self.cells.get(&cell).into_iter().flatten() {
+                let entry = self.entries[*key].as_ref().unwrap();
+                let entry_range = entry.range.unwrap();
+
+                // Report each key only from the first cell it shares with the query
+                let first = (
+                    i64::max(range.min.0, entry_range.min.0),
+                    i64::max(range.min.1, entry_range.min.1),
+                );
+
+                if cell == first && entry.bounds.overlaps(bounds) {
+                    found.push(*key);
+                }
+            }
+        }
+
+        for key in self.unbounded.iter() {
+            if self.bounds(*key).overlaps(bounds) {
+                found.push(*key);
+            }
+        }
+
+        found
+    }
+
+    pub fn pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+
+        for (cell, keys) in self.cells.iter() {
+            for (i, first) in keys.iter().enumerate() {
+                let first_entry = self.entries[*first].as_ref().unwrap();
+                let first_range = first_entry.range.unwrap();
+
+                for second in keys.iter().skip(i + 1) {
+                    let second_entry = self.entries[*second].as_ref().unwrap();
+                    let second_range = second_entry.range.unwrap();
+
+                    // Pairs spanning several cells are only reported from the first cell they share
+                    let shared = (
+                        i64::max(first_range.min.0, second_range.min.0),
+                        i64::max(first_range.min.1, second_range.min.1),
+                    );
+
+                    if *cell == shared && first_entry.bounds.overlaps(&second_entry.bounds) {
+                        pairs.push((usize::min(*first, *second), usize::max(*first, *second)));
+                    }
+                }
+            }
+        }
+
+        for first in self.unbounded.iter() {
+            for (second, entry) in self.entries.iter().enumerate() {
+                let Some(entry) = entry else {
+                    continue;
+                };
+
+                // Two unbounded entries are only paired once, from the lower key
+                if second == *first || (entry.range.is_none() && second < *first) {
+                    continue;
+                }
+
+                if self.bounds(*first).overlaps(&entry.bounds) {
+                    pairs.push((usize::min(*first, second), usize::max(*first, second)));
+                }
+            }
+        }
+
+        pairs
+    }
+
+    // None for boxes that are not finite or would cover too many cells to walk
+    fn range(&self, bounds: &BoundingBox<T>) -> Option<CellRange> {
+        let range = CellRange {
+            min: self.cell(bounds.min.x, bounds.min.y)?,
+            max: self.cell(bounds.max.x, bounds.max.y)?,
+        };
+
+        (range.count()? <= MAX_ENTRY_CELLS).then_some(range)
+    }
+
+    fn add_to_cells(&mut self, key: usize, range: Option<CellRange>) {
+        let Some(range) = range else {
+            self.unbounded.push(key);
+            return;
+        };
+
+        for cell in range.cells() {
+            self.cells.entry(cell).or_default().push(key);
+        }
+    }
+
+    fn remove_from_cells(&mut self, key: usize, range: Option<CellRange>) {
+        let Some(range) = range else {
+            self.unbounded.retain(|k| *k != key);
+            return;
+        };
+
+        for cell in range.cells() {
+            if let Some(keys) = self.cells.get_mut(&cell) {
+                keys.retain(|k| *k != key);
+
+                if keys.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod spatial_hash_tests {
+
+    use super::SpatialHash;
+    use crate::{
+        broad::bounding_box::BoundingBox,
+        narrow::{sat::SATable, shapes::circle::Circle},
+        vec2::Vec2,
+    };
+
+    fn brute_force_pairs(hash: &SpatialHash<f64>, keys: &[usize]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+
+        for (i, first) in keys.iter().enumerate() {
+            for second in keys.iter().skip(i + 1) {
+                if hash.bounds(*first).overlaps(hash.bounds(*second)) {
+                    pairs.push((usize::min(*first, *second), usize::max(*first, *second)));
+                }
+            }
+        }
+
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn test_cell() {
+        let hash = SpatialHash::new(2.0);
+
+        assert_eq!(hash.cell(0.0, 0.0), Some((0, 0)));
+        assert_eq!(hash.cell(3.9, 4.0), Some((1, 2)));
+        assert_eq!(hash.cell(-0.1, -4.1), Some((-1, -3)));
+        assert_eq!(hash.cell(f64::NAN, 0.0), None);
+        assert_eq!(hash.cell(0.0, f64::MAX), None);
+
+        assert!(SpatialHash::try_new(0.0).is_err());
+        assert!(SpatialHash::try_new(-1.0).is_err());
+        assert!(SpatialHash::try_new(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let mut hash = SpatialHash::new(1.0);
+        let mut keys = vec![
+            hash.insert(BoundingBox::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0))),
+            hash.insert(BoundingBox::new(Vec2::new(5.0, 5.0), Vec2::new(6.0, 6.0))),
+            hash.insert(BoundingBox::new(
+                Vec2::new(f64::MIN, f64::MIN),
+                Vec2::new(f64::MAX, 0.5),
+            )),
+            hash.insert(BoundingBox::new(
+                Vec2::new(-1.0e6, -1.0e6),
+                Vec2::new(1.0e6, 1.0e6),
+            )),
+            hash.insert(BoundingBox::new(
+                Vec2::new(f64::NAN, 0.0),
+                Vec2::new(1.0, 1.0),
+            )),
+        ];
+
+        let mut pairs = hash.pairs();
+        pairs.sort();
+        assert_eq!(pairs, brute_force_pairs(&hash, &keys));
+        assert_eq!(pairs.len(), 4);
+
+        let found = hash.query(&BoundingBox::new(
+            Vec2::new(4.0, 4.0),
+            Vec2::new(f64::INFINITY, f64::INFINITY),
+        ));
+        assert_eq!(found.len(), 2);
+        assert_eq!(
+            hash.query(&BoundingBox::new(Vec2::new(0.2, 0.2), Vec2::new(0.4, 0.4)))
+                .len(),
+            3
+        );
+
+        hash.update(
+            keys[2],
+            BoundingBox::new(Vec2::new(7.0, 7.0), Vec2::new(8.0, 8.0)),
+        );
+        hash.remove(keys.remove(3));
+
+        let mut pairs = hash.pairs();
+        pairs.sort();
+        assert_eq!(pairs, brute_force_pairs(&hash, &keys));
+        assert!(pairs.is_empty());
+    }
+
+    #[test]
+    fn test_pairs() {
+        let mut hash = SpatialHash::new(1.0);
+        let mut keys = Vec::new();
+
+        for i in 0..20 {
+            let min = Vec2::new((i * 7 % 13) as f64 * 0.4, (i * 5 % 11) as f64 * 0.3);
+            let size = Vec2::new(0.3 + (i % 4) as f64 * 0.5, 0.5);
+            keys.push(hash.insert(BoundingBox::new(min, min + size)));
+        }
+
+        let mut pairs = hash.pairs();
+        pairs.sort();
+        assert_eq!(pairs, brute_force_pairs(&hash, &keys));
+
+        for (i, key) in keys.iter().enumerate().step_by(3) {
+            let min = Vec2::new(i as f64 * 0.2, 1.0);
+            hash.update(*key, BoundingBox::new(min, min + Vec2::new(0.75, 2.5)));
+        }
+
+        for key in keys.drain(..5) {
+            hash.remove(key);
+        }
+
+        let mut pairs = hash.pairs();
+        pairs.sort();
+        assert_eq!(pairs, brute_force_pairs(&hash, &keys));
+    }
+
+    #[test]
+    fn test_query() {
+        let mut hash = SpatialHash::new(1.0);
+        let wide = hash.insert(BoundingBox::new(Vec2::new(-3.0, 0.0), Vec2::new(3.0, 0.5)));
+        let small = hash.insert(BoundingBox::new(Vec2::new(2.2, 2.2), Vec2::new(2.4, 2.4)));
+
+        let found = hash.query(&BoundingBox::new(
+            Vec2::new(-2.5, -2.5),
+            Vec2::new(2.5, 2.5),
+        ));
+        assert_eq!(found.len(), 2);
+        assert!(found.contains(&wide));
+        assert!(found.contains(&small));
+
+        assert!(hash
+            .query(&BoundingBox::new(Vec2::new(5.0, 5.0), Vec2::new(6.0, 6.0)))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_rebuild_with_circles() {
+        let circle = Circle::new(0.5);
+        let positions = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.8, 0.0),
+            Vec2::new(0.9, 0.9),
+            Vec2::new(5.0, 5.0),
+        ];
+
+        let mut hash = SpatialHash::new(1.0);
+        for _frame in 0..2 {
+            hash.clear();
+            for position in positions.iter() {
+                hash.insert(circle.bounding_box(*position));
+            }
+
+            let colliding: Vec<(usize, usize)> = hash
+                .pairs()
+                .into_iter()
+                .filter(|(a, b)| circle.collides(positions[*a], &circle, positions[*b]))
+                .collect();
+
+            assert_eq!(hash.pairs().len(), 3);
+            assert_eq!(colliding.len(), 2);
+        }
+    }
+}
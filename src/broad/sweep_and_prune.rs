@@ -0,0 +1,299 @@
+use std::collections::HashSet;
+
+use crate::{broad::bounding_box::BoundingBox, utility::Projection, NumTolerance};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SweepAxes {
+    X,
+    Y,
+    Both,
+}
+
+pub struct PairChanges {
+    pub added: Vec<(usize, usize)>,
+    pub removed: Vec<(usize, usize)>,
+}
+
+#[derive(Clone, Copy)]
+struct Endpoint<T: NumTolerance> {
+    value: T,
+    proxy: usize,
+    is_min: bool,
+}
+
+struct Proxy<T: NumTolerance> {
+    x: Projection<T>,
+    y: Projection<T>,
+}
+
+impl<T: NumTolerance> Proxy<T> {
+    fn from_bounds(bounds: &BoundingBox<T>) -> Self {
+        Self {
+            x: Projection {
+                min: bounds.min.x,
+                max: bounds.max.x,
+            },
+            y: Projection {
+                min: bounds.min.y,
+                max: bounds.max.y,
+            },
+        }
+    }
+
+    fn overlaps(&self, rhs: &Proxy<T>) -> bool {
+        self.x.overlaps(&rhs.x) && self.y.overlaps(&rhs.y)
+    }
+}
+
+fn ordered(first: usize, second: usize) -> (usize, usize) {
+    (usize::min(first, second), usize::max(first, second))
+}
+
+pub struct SweepAndPrune<T: NumTolerance> {
+    axes: SweepAxes,
+    proxies: Vec<Option<Proxy<T>>>,
+    free: Vec<usize>,
+    x_endpoints: Vec<Endpoint<T>>,
+    y_endpoints: Vec<Endpoint<T>>,
+    candidates: HashSet<(usize, usize)>,
+    overlaps: HashSet<(usize, usize)>,
+}
+
+impl<T: NumTolerance> SweepAndPrune<T> {
+    pub fn new(axes: SweepAxes) -> Self {
+        Self {
+            axes,
+            proxies: Vec::new(),
+            free: Vec::new(),
+            x_endpoints: Vec::new(),
+            y_endpoints: Vec::new(),
+            candidates: HashSet::new(),
+            overlaps: HashSet::new(),
+        }
+    }
+
+    pub fn insert(&mut self, bounds: BoundingBox<T>) -> usize {
+        let proxy = Proxy::from_bounds(&bounds);
+        let key = match self.free.pop() {
+            Some(key) => key,
+            None => {
+                self.proxies.push(None);
+                self.proxies.len() - 1
+            }
+        };
+
+        // New endpoints are appended unsorted and find their place during the next update
+        for (endpoints, projection) in [
+            (&mut self.x_endpoints, proxy.x),
+            (&mut self.y_endpoints, proxy.y),
+        ] {
+            endpoints.push(Endpoint {
+                value: projection.min,
+                proxy: key,
+                is_min: true,
+            });
+            endpoints.push(Endpoint {
+                value: projection.max,
+                proxy: key,
+                is_min: false,
+            });
+        }
+
+        self.proxies[key] = Some(proxy);
+
+        key
+    }
+
+    pub fn set_bounds(&mut self, key: usize, bounds: BoundingBox<T>) {
+        self.proxies[key] = Some(Proxy::from_bounds(&bounds));
+    }
+
+    pub fn remove(&mut self, key: usize) {
+        self.proxies[key].take().unwrap();
+        self.free.push(key);
+
+        self.x_endpoints.retain(|e| e.proxy != key);
+        self.y_endpoints.retain(|e| e.proxy != key);
+        self.candidates.retain(|(a, b)| *a != key && *b != key);
+    }
+
+    pub fn pairs(&self) -> impl Iterator<Item = &(usize, usize)> {
+        self.overlaps.iter()
+    }
+
+    pub fn update(&mut self) -> PairChanges {
+        let sort_x = self.axes != SweepAxes::Y;
+        let sort_y = self.axes != SweepAxes::X;
+
+        let mut x_endpoints = std::mem::take(&mut self.x_endpoints);
+        let mut y_endpoints = std::mem::take(&mut self.y_endpoints);
+
+        if sort_x {
+            self.refresh(&mut x_endpoints, |proxy| proxy.x);
+            self.insertion_sort(&mut x_endpoints);
+        }
+        if sort_y {
+            self.refresh(&mut y_endpoints, |proxy| proxy.y);
+            self.insertion_sort(&mut y_endpoints);
+        }
+
+        self.x_endpoints = x_endpoints;
+        self.y_endpoints = y_endpoints;
+
+        // Candidates only track the sorted axes, so the other axis is filtered here
+        let overlaps: HashSet<(usize, usize)> = self
+            .candidates
+            .iter()
+            .filter(|(a, b)| self.proxy(*a).overlaps(self.proxy(*b)))
+            .copied()
+            .collect();
+
+        let mut added: Vec<(usize, usize)> = overlaps.difference(&self.overlaps).copied().collect();
+        let mut removed: Vec<(usize, usize)> =
+            self.overlaps.difference(&overlaps).copied().collect();
+        added.sort();
+        removed.sort();
+
+        self.overlaps = overlaps;
+
+        PairChanges { added, removed }
+    }
+
+    fn proxy(&self, key: usize) -> &Proxy<T> {
+        self.proxies[key].as_ref().unwrap()
+    }
+
+    fn refresh(&self, endpoints: &mut [Endpoint<T>], axis: impl Fn(&Proxy<T>) -> Projection<T>) {
+        for endpoint in endpoints.iter_mut() {
+            let projection = axis(self.proxy(endpoint.proxy));
+
+            endpoint.value = match endpoint.is_min {
+                true => projection.min,
+                false => projection.max,
+            };
+        }
+    }
+
+    fn overlaps_sorted(&self, first: usize, second: usize) -> bool {
+        let (first, second) = (self.proxy(first), self.proxy(second));
+
+        match self.axes {
+            SweepAxes::X => first.x.overlaps(&second.x),
+            SweepAxes::Y => first.y.overlaps(&second.y),
+            SweepAxes::Both => first.overlaps(second),
+        }
+    }
+
+    // Coherent motion leaves the list nearly sorted, so each swap is a pair entering or leaving overlap on this axis
+    fn insertion_sort(&mut self, endpoints: &mut [Endpoint<T>]) {
+        for i in 1..endpoints.len() {
+            let mut j = i;
+
+            while j > 0 && endpoints[j - 1].value > endpoints[j].value {
+                let moving = endpoints[j];
+                let passed = endpoints[j - 1];
+
+                if moving.is_min && !passed.is_min {
+                    if self.overlaps_sorted(moving.proxy, passed.proxy) {
+                        self.candidates.insert(ordered(moving.proxy, passed.proxy));
+                    }
+                } else if !moving.is_min && passed.is_min {
+                    self.candidates.remove(&ordered(moving.proxy, passed.proxy));
+                }
+
+                endpoints.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod sweep_and_prune_tests {
+
+    use super::{SweepAndPrune, SweepAxes};
+    use crate::{broad::bounding_box::BoundingBox, vec2::Vec2};
+
+    fn square(x: f64, y: f64) -> BoundingBox<f64> {
+        BoundingBox::new(Vec2::new(x, y), Vec2::new(x + 1.0, y + 1.0))
+    }
+
+    fn brute_force_pairs(boxes: &[BoundingBox<f64>]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+
+        for i in 0..boxes.len() {
+            for j in (i + 1)..boxes.len() {
+                if boxes[i].overlaps(&boxes[j]) {
+                    pairs.push((i, j));
+                }
+            }
+        }
+
+        pairs
+    }
+
+    fn sorted_pairs(sap: &SweepAndPrune<f64>) -> Vec<(usize, usize)> {
+        let mut pairs: Vec<(usize, usize)> = sap.pairs().copied().collect();
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn test_added_and_removed() {
+        let mut sap = SweepAndPrune::new(SweepAxes::Both);
+        let a = sap.insert(square(0.0, 0.0));
+        let b = sap.insert(square(0.5, 0.5));
+        let c = sap.insert(square(5.0, 0.0));
+
+        let changes = sap.update();
+        assert_eq!(changes.added, vec![(a, b)]);
+        assert!(changes.removed.is_empty());
+
+        sap.set_bounds(c, square(1.2, 1.2));
+        let changes = sap.update();
+        assert_eq!(changes.added, vec![(b, c)]);
+        assert!(changes.removed.is_empty());
+
+        sap.set_bounds(a, square(-3.0, 0.0));
+        let changes = sap.update();
+        assert!(changes.added.is_empty());
+        assert_eq!(changes.removed, vec![(a, b)]);
+
+        let changes = sap.update();
+        assert!(changes.added.is_empty());
+        assert!(changes.removed.is_empty());
+
+        sap.remove(b);
+        let changes = sap.update();
+        assert!(changes.added.is_empty());
+        assert_eq!(changes.removed, vec![(b, c)]);
+    }
+
+    #[test]
+    fn test_coherent_motion() {
+        for axes in [SweepAxes::X, SweepAxes::Y, SweepAxes::Both] {
+            let mut sap = SweepAndPrune::new(axes);
+            let mut boxes: Vec<BoundingBox<f64>> = (0..30)
+                .map(|i| square((i % 6) as f64 * 1.3, (i / 6) as f64 * 1.1))
+                .collect();
+
+            for bounds in boxes.iter() {
+                sap.insert(*bounds);
+            }
+            sap.update();
+            assert_eq!(sorted_pairs(&sap), brute_force_pairs(&boxes));
+
+            for frame in 0..20 {
+                for (i, bounds) in boxes.iter_mut().enumerate() {
+                    let t = (frame * 7 + i * 3) as f64;
+                    let step = Vec2::new(f64::sin(t) * 0.15, f64::cos(t * 1.3) * 0.15);
+                    *bounds = BoundingBox::new(bounds.min + step, bounds.max + step);
+                    sap.set_bounds(i, *bounds);
+                }
+
+                sap.update();
+                assert_eq!(sorted_pairs(&sap), brute_force_pairs(&boxes));
+            }
+        }
+    }
+}
@@ -4,6 +4,7 @@ use std::fmt::Display;
 pub mod broad;
 pub mod narrow;
 
+pub mod transform;
 pub mod utility;
 pub mod vec2;
 
@@ -0,0 +1,259 @@
+use num::clamp;
+
+use crate::{
+    narrow::{
+        epa::{converged, expand},
+        gjk::{gjk_simplex, Supportable},
+    },
+    transform::Transform,
+    vec2::Vec2,
+    NumTolerance,
+};
+
+static DISTANCE_MAX_ITERATIONS: usize = 128;
+
+// Negative distances are penetration depths, and `point_b` always sits at `point_a + normal * distance`
+pub struct DistanceResult<T: NumTolerance> {
+    pub distance: T,
+    pub point_a: Vec2<T>,
+    pub point_b: Vec2<T>,
+    pub normal: Vec2<T>,
+}
+
+#[derive(Clone, Copy)]
+struct SupportPoint<T: NumTolerance> {
+    point: Vec2<T>,
+    a: Vec2<T>,
+    b: Vec2<T>,
+}
+
+impl<T: NumTolerance> SupportPoint<T> {
+    fn lerp(&self, other: &SupportPoint<T>, t: T) -> SupportPoint<T> {
+        SupportPoint {
+            point: self.point + (other.point - self.point).scale(t),
+            a: self.a + (other.a - self.a).scale(t),
+            b: self.b + (other.b - self.b).scale(t),
+        }
+    }
+}
+
+pub fn distance<T: NumTolerance>(
+    shape: &(impl Supportable<T> + ?Sized),
+    transform: impl Into<Transform<T>>,
+    other: &(impl Supportable<T> + ?Sized),
+    other_transform: impl Into<Transform<T>>,
+) -> DistanceResult<T> {
+    let (transform, other_transform) = (transform.into(), other_transform.into());
+
+    // The closest, or most deeply overlapping, piece decides the distance to the whole
+    let pieces = match (shape.support_pieces(), other.support_pieces()) {
+        (Some(pieces), _) => Some(
+            pieces
+                .iter()
+                .map(|piece| distance(piece, transform, other, other_transform))
+                .collect::<Vec<_>>(),
+        ),
+        (None, Some(pieces)) => Some(
+            pieces
+                .iter()
+                .map(|piece| distance(shape, transform, piece, other_transform))
+                .collect(),
+        ),
+        (None, None) => None,
+    };
+    if let Some(results) = pieces {
+        return results
+            .into_iter()
+            .min_by(|result, other| result.distance.partial_cmp(&other.distance).unwrap())
+            .unwrap();
+    }
+
+    if let Some(simplex) = gjk_simplex(shape, transform, other, other_transform) {
+        let resolution = expand(shape, transform, other, other_transform, simplex);
+        let normal = -resolution.axis;
+        let point_a = shape.support(transform, normal);
+
+        return DistanceResult {
+            distance: -resolution.penetration,
+            point_a,
+            point_b: point_a - normal.scale(resolution.penetration),
+            normal,
+        };
+    }
+
+    let support = |direction: Vec2<T>| {
+        let a = shape.support(transform, direction);
+        let b = other.support(other_transform, -direction);
+
+        SupportPoint { point: a - b, a, b }
+    };
+
+    let mut direction = other_transform.translation - transform.translation;
+    if direction.length_squared().is_trivial_abs() {
+        direction = Vec2::new(T::one(), T::zero());
+    }
+
+    let mut simplex = vec![support(-direction)];
+    let mut closest = simplex[0];
+    let mut normal = direction.normalized();
+
+    for _ in 0..DISTANCE_MAX_ITERATIONS {
+        let v = closest.point;
+        if v.length_squared().is_zero() {
+            break;
+        }
+        normal = -v.normalized();
+
+        let next = support(-v);
+        let length = v.length();
+        if converged(v.dot(next.point) / length, length, next.point.length()) {
+            break;
+        }
+
+        simplex.push(next);
+        closest = reduce_simplex(&mut simplex);
+    }
+
+    DistanceResult {
+        distance: closest.point.length(),
+        point_a: closest.a,
+        point_b: closest.b,
+        normal,
+    }
+}
+
+// Shrinks the simplex to the feature closest to the origin and returns the closest point on it
+fn reduce_simplex<T: NumTolerance>(simplex: &mut Vec<SupportPoint<T>>) -> SupportPoint<T> {
+    let edges: Vec<(usize, usize)> = match simplex.len() {
+        2 => vec![(0, 1)],
+        _ => vec![(0, 1), (1, 2), (2, 0)],
+    };
+
+    let mut best: Option<(SupportPoint<T>, Vec<SupportPoint<T>>)> = None;
+    for (i, j) in edges {
+        let (start, end) = (simplex[i], simplex[j]);
+        let edge = end.point - start.point;
+
+        let t = match edge.length_squared().is_zero() {
+            true => T::zero(),
+            false => clamp(
+                -start.point.dot(edge) / edge.length_squared(),
+                T::zero(),
+                T::one(),
+            ),
+        };
+        let candidate = start.lerp(&end, t);
+
+        let is_closer = match &best {
+            Some((point, _)) => candidate.point.length_squared() < point.point.length_squared(),
+            None => true,
+        };
+        if is_closer {
+            let feature = match t {
+                t if t.is_zero() => vec![start],
+                t if t == T::one() => vec![end],
+                _ => vec![start, end],
+            };
+            best = Some((candidate, feature));
+        }
+    }
+
+    let (closest, feature) = best.unwrap();
+    *simplex = feature;
+
+    closest
+}
+
+#[cfg(test)]
+mod distance_tests {
+
+    use float_eq::assert_float_eq;
+    use std::f64::consts::FRAC_PI_4;
+
+    use super::distance;
+    use crate::{
+        narrow::shapes::{aabb::AABB, capsule::Capsule, circle::Circle, polygon::Polygon},
+        transform::Transform,
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_separated() {
+        let circ = Circle::new(1.0);
+        let aabb = AABB::new(2.0, 2.0);
+
+        let result = distance(&circ, Vec2::zero(), &circ, Vec2::new(3.0, 4.0));
+        assert_float_eq!(result.distance, 3.0, abs <= 0.0001);
+        assert_float_eq!(result.point_a.x, 0.6, abs <= 0.0001);
+        assert_float_eq!(result.point_a.y, 0.8, abs <= 0.0001);
+        assert_float_eq!(result.point_b.x, 2.4, abs <= 0.0001);
+        assert_float_eq!(result.point_b.y, 3.2, abs <= 0.0001);
+        assert_float_eq!(result.normal.x, 0.6, abs <= 0.0001);
+        assert_float_eq!(result.normal.y, 0.8, abs <= 0.0001);
+
+        let result = distance(&aabb, Vec2::zero(), &aabb, Vec2::new(3.5, 0.5));
+        assert_float_eq!(result.distance, 1.5, abs <= 0.0001);
+        assert_float_eq!(result.point_a.x, 1.0, abs <= 0.0001);
+        assert_float_eq!(result.point_b.x, 2.5, abs <= 0.0001);
+        assert_float_eq!(result.normal.x, 1.0, abs <= 0.0001);
+        assert_float_eq!(result.normal.y, 0.0, abs <= 0.0001);
+
+        let result = distance(
+            &aabb,
+            Transform::new(Vec2::zero(), FRAC_PI_4, 1.0),
+            &circ,
+            Vec2::new(4.0, 0.0),
+        );
+        assert_float_eq!(result.distance, 3.0 - f64::sqrt(2.0), abs <= 0.0001);
+        assert_float_eq!(result.point_a.x, f64::sqrt(2.0), abs <= 0.0001);
+        assert_float_eq!(result.point_b.x, 3.0, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_capsule_polygon() {
+        let cap = Capsule::new(Vec2::new(0.0, 1.0), 0.5);
+        let poly = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.5, 2.0),
+            Vec2::new(0.0, 1.0),
+        ])
+        .unwrap();
+
+        let result = distance(&poly, Vec2::zero(), &cap, Vec2::new(2.5, 0.5));
+        assert_float_eq!(result.distance, 1.0, abs <= 0.0001);
+        assert_float_eq!(result.point_a.x, 1.0, abs <= 0.0001);
+        assert_float_eq!(result.point_b.x, 2.0, abs <= 0.0001);
+        assert_float_eq!(result.point_b.y, result.point_a.y, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let circ = Circle::new(1.0);
+        let aabb = AABB::new(2.0, 2.0);
+
+        let result = distance(&aabb, Vec2::zero(), &circ, Vec2::new(0.0, 1.5));
+        assert_float_eq!(result.distance, -0.5, abs <= 0.0001);
+        assert_float_eq!(result.normal.x, 0.0, abs <= 0.0001);
+        assert_float_eq!(result.normal.y, 1.0, abs <= 0.0001);
+        assert_float_eq!(
+            result.point_b.y,
+            result.point_a.y + result.normal.y * result.distance,
+            abs <= 0.0001
+        );
+
+        let result = distance(&aabb, Vec2::zero(), &aabb, Vec2::new(2.0, 0.0));
+        assert_float_eq!(result.distance, 0.0, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_f32() {
+        let circ = Circle::new(1.0_f32);
+
+        let result = distance(&circ, Vec2::zero(), &circ, Vec2::new(30.0, 40.0));
+        assert_float_eq!(result.distance, 48.0, abs <= 0.001);
+        assert_float_eq!(result.normal.x, 0.6, abs <= 0.0001);
+        assert_float_eq!(result.point_b.y, 39.2, abs <= 0.001);
+    }
+}
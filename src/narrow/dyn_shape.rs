@@ -0,0 +1,316 @@
+use crate::{
+    broad::bounding_box::BoundingBox,
+    narrow::{
+        sat::{Axis, Resolution, SATable},
+        shapes::{polygon::Polygon, ShapeType, Shapeable},
+    },
+    transform::Transform,
+    utility::Projection,
+    vec2::Vec2,
+    NumTolerance,
+};
+
+// Object safe mirror of `SATable` and `Shapeable`, so shapes of different types can share a `Vec<Box<dyn DynShape<T>>>`
+pub trait DynShape<T: NumTolerance> {
+    fn dyn_axes(&self, transform: Transform<T>) -> Vec<Axis<T>>;
+
+    fn dyn_project(&self, axis: Vec2<T>, transform: Transform<T>) -> Projection<T>;
+
+    fn dyn_bounding_box(&self, transform: Transform<T>) -> BoundingBox<T>;
+
+    fn dyn_axis_from_point(&self, transform: Transform<T>, point: Vec2<T>) -> Vec2<T>;
+
+    fn dyn_contains_point(&self, transform: Transform<T>, point: Vec2<T>) -> bool;
+
+    fn dyn_convex_pieces(&self) -> Option<&[Polygon<T>]>;
+
+    fn dyn_shape(&self) -> ShapeType<'_, T>;
+
+    fn dyn_collides(
+        &self,
+        transform: Transform<T>,
+        shape: &dyn DynShape<T>,
+        shape_transform: Transform<T>,
+    ) -> bool;
+
+    fn dyn_collision_resolution(
+        &self,
+        transform: Transform<T>,
+        shape: &dyn DynShape<T>,
+        shape_transform: Transform<T>,
+    ) -> Resolution<T>;
+}
+
+// The other shape still reports its `ShapeType`, so the specialised pairs are taken just as on the static path
+impl<T, S> DynShape<T> for S
+where
+    T: NumTolerance,
+    S: SATable<T> + Shapeable<T>,
+{
+    fn dyn_axes(&self, transform: Transform<T>) -> Vec<Axis<T>> {
+        self.axes(transform).collect()
+    }
+
+    fn dyn_project(&self, axis: Vec2<T>, transform: Transform<T>) -> Projection<T> {
+        self.project(axis, transform)
+    }
+
+    fn dyn_bounding_box(&self, transform: Transform<T>) -> BoundingBox<T> {
+        self.bounding_box(transform)
+    }
+
+    fn dyn_axis_from_point(&self, transform: Transform<T>, point: Vec2<T>) -> Vec2<T> {
+        self.axis_from_point(transform, point)
+    }
+
+    fn dyn_contains_point(&self, transform: Transform<T>, point: Vec2<T>) -> bool {
+        self.contains_point(transform, point)
+    }
+
+    fn dyn_convex_pieces(&self) -> Option<&[Polygon<T>]> {
+        self.convex_pieces()
+    }
+
+    fn dyn_shape(&self) -> ShapeType<'_, T> {
+        self.shape()
+    }
+
+    fn dyn_collides(
+        &self,
+        transform: Transform<T>,
+        shape: &dyn DynShape<T>,
+        shape_transform: Transform<T>,
+    ) -> bool {
+        self.collides(transform, &shape, shape_transform)
+    }
+
+    fn dyn_collision_resolution(
+        &self,
+        transform: Transform<T>,
+        shape: &dyn DynShape<T>,
+        shape_transform: Transform<T>,
+    ) -> Resolution<T> {
+        self.collision_resolution(transform, &shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> SATable<T> for dyn DynShape<T> + '_ {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        self.dyn_axes(transform.into()).into_iter()
+    }
+
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        self.dyn_project(axis, transform.into())
+    }
+
+    fn bounding_box(&self, transform: impl Into<Transform<T>>) -> BoundingBox<T> {
+        self.dyn_bounding_box(transform.into())
+    }
+
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        self.dyn_axis_from_point(transform.into(), point)
+    }
+
+    fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
+        self.dyn_contains_point(transform.into(), point)
+    }
+
+    fn convex_pieces(&self) -> Option<&[Polygon<T>]> {
+        self.dyn_convex_pieces()
+    }
+
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        self.dyn_collides(transform.into(), shape, shape_transform.into())
+    }
+
+    fn collision_resolution<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T>
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        self.dyn_collision_resolution(transform.into(), shape, shape_transform.into())
+    }
+}
+
+impl<T: NumTolerance> Shapeable<T> for dyn DynShape<T> + '_ {
+    fn shape(&self) -> ShapeType<'_, T> {
+        self.dyn_shape()
+    }
+}
+
+// Lets boxed and borrowed shapes, including trait objects, be passed wherever a sized shape is expected
+macro_rules! forward_shape {
+    ($($pointer:ty),*) => {
+        $(impl<T: NumTolerance, S: SATable<T> + ?Sized> SATable<T> for $pointer {
+            fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+                (**self).axes(transform)
+            }
+
+            fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+                (**self).project(axis, transform)
+            }
+
+            fn bounding_box(&self, transform: impl Into<Transform<T>>) -> BoundingBox<T> {
+                (**self).bounding_box(transform)
+            }
+
+            fn axis_from_point(
+                &self,
+                transform: impl Into<Transform<T>>,
+                point: Vec2<T>,
+            ) -> Vec2<T> {
+                (**self).axis_from_point(transform, point)
+            }
+
+            fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
+                (**self).contains_point(transform, point)
+            }
+
+            fn convex_pieces(&self) -> Option<&[Polygon<T>]> {
+                (**self).convex_pieces()
+            }
+
+            fn collides<O>(
+                &self,
+                transform: impl Into<Transform<T>>,
+                shape: &O,
+                shape_transform: impl Into<Transform<T>>,
+            ) -> bool
+            where
+                O: SATable<T> + Shapeable<T>,
+            {
+                (**self).collides(transform, shape, shape_transform)
+            }
+
+            fn collision_resolution<O>(
+                &self,
+                transform: impl Into<Transform<T>>,
+                shape: &O,
+                shape_transform: impl Into<Transform<T>>,
+            ) -> Resolution<T>
+            where
+                O: SATable<T> + Shapeable<T>,
+            {
+                (**self).collision_resolution(transform, shape, shape_transform)
+            }
+        }
+
+        impl<T: NumTolerance, S: Shapeable<T> + ?Sized> Shapeable<T> for $pointer {
+            fn shape(&self) -> ShapeType<'_, T> {
+                (**self).shape()
+            }
+        })*
+    };
+}
+
+forward_shape!(&S, Box<S>);
+
+#[cfg(test)]
+mod dyn_shape_tests {
+
+    use float_eq::assert_float_eq;
+    use std::f64::consts::FRAC_PI_4;
+
+    use super::DynShape;
+    use crate::{
+        narrow::{
+            sat::SATable,
+            shapes::{
+                aabb::AABB, capsule::Capsule, circle::Circle, concave::ConcavePolygon,
+                polygon::Polygon,
+            },
+        },
+        transform::Transform,
+        vec2::Vec2,
+    };
+
+    fn shapes() -> Vec<Box<dyn DynShape<f64>>> {
+        vec![
+            Box::new(AABB::new(2.0, 1.0)),
+            Box::new(Circle::new(1.0)),
+            Box::new(Capsule::new(Vec2::new(0.0, 1.0), 0.5)),
+            Box::new(
+                Polygon::new(vec![
+                    Vec2::new(0.0, 0.0),
+                    Vec2::new(1.0, 0.0),
+                    Vec2::new(0.0, 1.0),
+                ])
+                .unwrap(),
+            ),
+            Box::new(
+                ConcavePolygon::new(vec![
+                    Vec2::new(0.0, 0.0),
+                    Vec2::new(2.0, 0.0),
+                    Vec2::new(2.0, 1.0),
+                    Vec2::new(1.0, 1.0),
+                    Vec2::new(1.0, 2.0),
+                    Vec2::new(0.0, 2.0),
+                ])
+                .unwrap(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_matches_static() {
+        let aabb = AABB::new(2.0, 1.0);
+        let circ = Circle::new(1.0);
+        let boxed: Vec<Box<dyn DynShape<f64>>> =
+            vec![Box::new(AABB::new(2.0, 1.0)), Box::new(Circle::new(1.0))];
+        let positions = [
+            Vec2::new(1.5, 0.5),
+            Vec2::new(1.9, 1.0),
+            Vec2::new(2.5, 0.0),
+            Vec2::new(0.2, -0.3),
+        ];
+
+        for position in positions {
+            let expected = aabb.collision_resolution(Vec2::zero(), &circ, position);
+            let res = boxed[0].collision_resolution(Vec2::zero(), &boxed[1], position);
+
+            assert_eq!(res.colliding, expected.colliding);
+            assert_eq!(
+                boxed[0].collides(Vec2::zero(), &boxed[1], position),
+                aabb.collides(Vec2::zero(), &circ, position)
+            );
+            if expected.colliding {
+                assert_float_eq!(res.penetration, expected.penetration, abs <= 0.0001);
+                assert_float_eq!(res.axis.x, expected.axis.x, abs <= 0.0001);
+                assert_float_eq!(res.axis.y, expected.axis.y, abs <= 0.0001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_heterogeneous() {
+        let shapes = shapes();
+        let rotated = Transform::new(Vec2::new(0.2, 0.2), FRAC_PI_4, 1.0);
+
+        for shape in &shapes {
+            for other in &shapes {
+                assert!(shape.collides(Vec2::zero(), other, rotated));
+                assert!(
+                    shape
+                        .collision_resolution(Vec2::zero(), other, rotated)
+                        .colliding
+                );
+                assert!(!shape.collides(Vec2::zero(), other, Vec2::new(10.0, 0.0)));
+            }
+        }
+
+        assert!(shapes[1].contains_point(Vec2::zero(), Vec2::new(0.5, 0.5)));
+        assert!(!shapes[1].contains_point(Vec2::zero(), Vec2::new(1.5, 0.5)));
+    }
+}
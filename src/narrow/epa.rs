@@ -0,0 +1,221 @@
+use crate::{
+    narrow::{
+        gjk::{gjk_simplex, minkowski_support, Supportable},
+        sat::{deepest, Resolution},
+    },
+    transform::Transform,
+    vec2::Vec2,
+    NumTolerance,
+};
+
+static EPA_MAX_ITERATIONS: usize = 128;
+
+pub fn epa_collision_resolution<T: NumTolerance>(
+    shape: &(impl Supportable<T> + ?Sized),
+    transform: impl Into<Transform<T>>,
+    other: &(impl Supportable<T> + ?Sized),
+    other_transform: impl Into<Transform<T>>,
+) -> Resolution<T> {
+    let (transform, other_transform) = (transform.into(), other_transform.into());
+
+    if let Some(pieces) = shape.support_pieces() {
+        return deepest(
+            pieces
+                .iter()
+                .map(|piece| epa_collision_resolution(piece, transform, other, other_transform)),
+        );
+    }
+    if let Some(pieces) = other.support_pieces() {
+        return deepest(
+            pieces
+                .iter()
+                .map(|piece| epa_collision_resolution(shape, transform, piece, other_transform)),
+        );
+    }
+
+    match gjk_simplex(shape, transform, other, other_transform) {
+        Some(simplex) => expand(shape, transform, other, other_transform, simplex),
+        None => Resolution::new(),
+    }
+}
+
+// Grows the GJK simplex towards the edge of the Minkowski difference closest to the origin
+pub(crate) fn expand<T: NumTolerance>(
+    shape: &(impl Supportable<T> + ?Sized),
+    transform: Transform<T>,
+    other: &(impl Supportable<T> + ?Sized),
+    other_transform: Transform<T>,
+    simplex: Vec<Vec2<T>>,
+) -> Resolution<T> {
+    let support =
+        |direction| minkowski_support(shape, transform, other, other_transform, direction);
+
+    let mut polytope = initial_polytope(simplex, support);
+    let mut resolution = Resolution::new();
+
+    for _ in 0..EPA_MAX_ITERATIONS {
+        let (index, normal, distance) = closest_edge(&polytope);
+        resolution = Resolution {
+            colliding: true,
+            penetration: distance,
+            axis: -normal,
+        };
+
+        let point = support(normal);
+        if converged(distance, point.dot(normal), point.length()) {
+            break;
+        }
+
+        polytope.insert(index + 1, point);
+    }
+
+    resolution
+}
+
+// The normal on a curved boundary only settles within the crate tolerance once the bounds are within its square,
+// but they can never close past the rounding error carried by coordinates of the given magnitude
+pub(crate) fn converged<T: NumTolerance>(lower: T, upper: T, magnitude: T) -> bool {
+    let settled = lower.error_mixed(upper) * lower.error_abs();
+    let rounding = T::epsilon() * T::max(magnitude, T::one()) * T::from(16).unwrap();
+
+    upper - lower < T::max(settled, rounding)
+}
+
+// Winds the simplex counter-clockwise, widening it first if it has collapsed onto a line
+fn initial_polytope<T: NumTolerance>(
+    mut simplex: Vec<Vec2<T>>,
+    support: impl Fn(Vec2<T>) -> Vec2<T>,
+) -> Vec<Vec2<T>> {
+    let area = (simplex[1] - simplex[0]).cross(simplex[2] - simplex[0]);
+
+    if area.is_trivial_abs() {
+        let (start, end) = widest_pair(&simplex);
+        let normal = (end - start).rotate_counter_90().normalized();
+
+        return vec![start, support(-normal), end, support(normal)];
+    }
+
+    if area.is_sign_negative() {
+        simplex.swap(1, 2);
+    }
+
+    simplex
+}
+
+fn widest_pair<T: NumTolerance>(simplex: &[Vec2<T>]) -> (Vec2<T>, Vec2<T>) {
+    let pairs = [
+        (simplex[0], simplex[1]),
+        (simplex[1], simplex[2]),
+        (simplex[2], simplex[0]),
+    ];
+
+    pairs
+        .into_iter()
+        .max_by(|(a0, b0), (a1, b1)| {
+            (*b0 - *a0)
+                .length_squared()
+                .partial_cmp(&(*b1 - *a1).length_squared())
+                .unwrap()
+        })
+        .unwrap()
+}
+
+fn closest_edge<T: NumTolerance>(polytope: &[Vec2<T>]) -> (usize, Vec2<T>, T) {
+    let mut closest = (0, Vec2::zero(), T::max_value());
+
+    for (i, &start) in polytope.iter().enumerate() {
+        let edge = polytope[(i + 1) % polytope.len()] - start;
+        if edge.length_squared().is_zero() {
+            continue;
+        }
+
+        let normal = edge.rotate_clock_90().normalized();
+        let distance = normal.dot(start);
+
+        if distance < closest.2 {
+            closest = (i, normal, distance);
+        }
+    }
+
+    closest
+}
+
+#[cfg(test)]
+mod epa_tests {
+
+    use float_eq::assert_float_eq;
+    use std::f64::consts::FRAC_PI_4;
+
+    use super::{converged, epa_collision_resolution};
+    use crate::{
+        narrow::shapes::{aabb::AABB, capsule::Capsule, circle::Circle},
+        transform::Transform,
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_resolution() {
+        let circ = Circle::new(1.0);
+        let aabb = AABB::new(2.0, 2.0);
+        let cap = Capsule::new(Vec2::new(0.0, 1.0), 0.5);
+
+        let res = epa_collision_resolution(&circ, Vec2::zero(), &circ, Vec2::new(1.5, 0.0));
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.5, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, -1.0, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, 0.0, abs <= 0.0001);
+
+        let res = epa_collision_resolution(&aabb, Vec2::new(0.2, 1.5), &aabb, Vec2::zero());
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.5, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, 0.0, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, 1.0, abs <= 0.0001);
+
+        let res = epa_collision_resolution(
+            &aabb,
+            Transform::new(Vec2::zero(), FRAC_PI_4, 1.0),
+            &cap,
+            Vec2::new(1.5, 0.0),
+        );
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, f64::sqrt(2.0) - 1.0, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, -1.0, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, 0.0, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_degenerate_simplex() {
+        let aabb = AABB::new(2.0, 2.0);
+
+        let res = epa_collision_resolution(&aabb, Vec2::zero(), &aabb, Vec2::zero());
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 2.0, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_does_not_collide() {
+        let circ = Circle::new(1.0);
+
+        assert!(
+            !epa_collision_resolution(&circ, Vec2::zero(), &circ, Vec2::new(2.5, 0.0)).colliding
+        );
+        assert!(
+            !epa_collision_resolution(&circ, Vec2::zero(), &circ, Vec2::new(2.0, 0.0)).colliding
+        );
+    }
+
+    #[test]
+    fn test_converged() {
+        // f32 can't resolve the squared tolerance near 1, so its rounding error takes over
+        assert!(converged(0.5_f32, 0.5000002, 1.0));
+        assert!(!converged(0.5_f64, 0.5000002, 1.0));
+        assert!(converged(0.5_f64, 0.500000001, 1.0));
+        assert!(!converged(0.5_f32, 0.51, 1.0));
+
+        let circ = Circle::new(1.0_f32);
+        let res = epa_collision_resolution(&circ, Vec2::zero(), &circ, Vec2::new(0.0, 1.5));
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.5, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, -1.0, abs <= 0.0001);
+    }
+}
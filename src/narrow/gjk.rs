@@ -0,0 +1,266 @@
+use crate::{narrow::shapes::polygon::Polygon, transform::Transform, vec2::Vec2, NumTolerance};
+
+static GJK_MAX_ITERATIONS: usize = 64;
+
+pub trait Supportable<T: NumTolerance> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T>;
+
+    // Shapes that aren't convex hand back their convex pieces, since a support mapping only ever sees the hull
+    fn support_pieces(&self) -> Option<&[Polygon<T>]> {
+        None
+    }
+}
+
+pub fn gjk_collides<T: NumTolerance>(
+    shape: &(impl Supportable<T> + ?Sized),
+    transform: impl Into<Transform<T>>,
+    other: &(impl Supportable<T> + ?Sized),
+    other_transform: impl Into<Transform<T>>,
+) -> bool {
+    let (transform, other_transform) = (transform.into(), other_transform.into());
+
+    if let Some(pieces) = shape.support_pieces() {
+        return pieces
+            .iter()
+            .any(|piece| gjk_collides(piece, transform, other, other_transform));
+    }
+    if let Some(pieces) = other.support_pieces() {
+        return pieces
+            .iter()
+            .any(|piece| gjk_collides(shape, transform, piece, other_transform));
+    }
+
+    gjk_simplex(shape, transform, other, other_transform).is_some()
+}
+
+// Support point of the Minkowski difference `shape - other`, which contains the origin exactly when the shapes overlap
+pub(crate) fn minkowski_support<T: NumTolerance>(
+    shape: &(impl Supportable<T> + ?Sized),
+    transform: Transform<T>,
+    other: &(impl Supportable<T> + ?Sized),
+    other_transform: Transform<T>,
+    direction: Vec2<T>,
+) -> Vec2<T> {
+    shape.support(transform, direction) - other.support(other_transform, -direction)
+}
+
+// Returns a triangle of Minkowski difference points enclosing the origin if the shapes overlap
+pub(crate) fn gjk_simplex<T: NumTolerance>(
+    shape: &(impl Supportable<T> + ?Sized),
+    transform: Transform<T>,
+    other: &(impl Supportable<T> + ?Sized),
+    other_transform: Transform<T>,
+) -> Option<Vec<Vec2<T>>> {
+    let mut direction = transform.translation - other_transform.translation;
+    if direction.length_squared().is_trivial_abs() {
+        direction = Vec2::new(T::one(), T::zero());
+    }
+
+    let mut simplex = vec![minkowski_support(
+        shape,
+        transform,
+        other,
+        other_transform,
+        direction,
+    )];
+    direction = -simplex[0];
+
+    for _ in 0..GJK_MAX_ITERATIONS {
+        // The origin sits on the boundary of the difference, so the shapes are only touching
+        if direction.length_squared().is_trivial_abs() {
+            return None;
+        }
+
+        let direction_norm = direction.normalized();
+        let point = minkowski_support(shape, transform, other, other_transform, direction_norm);
+
+        if point.dot(direction_norm).is_difference_small(T::zero()) {
+            return None;
+        }
+
+        simplex.push(point);
+        if update_simplex(&mut simplex, &mut direction) {
+            return match on_boundary(shape, transform, other, other_transform, &simplex) {
+                true => None,
+                false => Some(simplex),
+            };
+        }
+    }
+
+    None
+}
+
+// The origin can lie on an edge of the enclosing triangle, which only counts as overlap if the difference extends past it
+fn on_boundary<T: NumTolerance>(
+    shape: &(impl Supportable<T> + ?Sized),
+    transform: Transform<T>,
+    other: &(impl Supportable<T> + ?Sized),
+    other_transform: Transform<T>,
+    simplex: &[Vec2<T>],
+) -> bool {
+    (0..3).any(|i| {
+        let (start, end, opposite) = (simplex[i], simplex[(i + 1) % 3], simplex[(i + 2) % 3]);
+        let normal = -towards((end - start).rotate_counter_90(), opposite - start).normalized();
+
+        if !start.dot(normal).is_trivial_abs() {
+            return false;
+        }
+
+        minkowski_support(shape, transform, other, other_transform, normal)
+            .dot(normal)
+            .is_difference_small(T::zero())
+    })
+}
+
+fn towards<T: NumTolerance>(vector: Vec2<T>, target: Vec2<T>) -> Vec2<T> {
+    match vector.dot(target).is_sign_negative() {
+        true => -vector,
+        false => vector,
+    }
+}
+
+fn update_simplex<T: NumTolerance>(simplex: &mut Vec<Vec2<T>>, direction: &mut Vec2<T>) -> bool {
+    let a = *simplex.last().unwrap();
+    let to_origin = -a;
+
+    match simplex.len() {
+        2 => {
+            let ab = simplex[0] - a;
+
+            match ab.dot(to_origin).is_sign_positive() {
+                true => *direction = towards(ab.rotate_counter_90(), to_origin),
+                false => {
+                    *simplex = vec![a];
+                    *direction = to_origin;
+                }
+            }
+
+            false
+        }
+        _ => {
+            let (c, b) = (simplex[0], simplex[1]);
+            let (ab, ac) = (b - a, c - a);
+
+            let ab_normal = -towards(ab.rotate_counter_90(), ac);
+            let ac_normal = -towards(ac.rotate_counter_90(), ab);
+
+            if ab_normal.dot(to_origin) > T::zero() {
+                *simplex = vec![b, a];
+                *direction = ab_normal;
+                false
+            } else if ac_normal.dot(to_origin) > T::zero() {
+                *simplex = vec![c, a];
+                *direction = ac_normal;
+                false
+            } else {
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod gjk_tests {
+
+    use float_eq::assert_float_eq;
+    use std::f64::consts::FRAC_PI_4;
+
+    use super::{gjk_collides, Supportable};
+    use crate::{
+        narrow::shapes::{aabb::AABB, capsule::Capsule, circle::Circle, polygon::Polygon},
+        transform::Transform,
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_support() {
+        let circ = Circle::new(2.0);
+        let point = circ.support(Vec2::new(1.0, 1.0), Vec2::new(0.0, -3.0));
+        assert_float_eq!(point.x, 1.0, abs <= 0.0001);
+        assert_float_eq!(point.y, -1.0, abs <= 0.0001);
+
+        let cap = Capsule::new(Vec2::new(1.0, 0.0), 0.5);
+        let point = cap.support(Vec2::zero(), Vec2::new(-1.0, 0.0));
+        assert_float_eq!(point.x, -1.5, abs <= 0.0001);
+        assert_float_eq!(point.y, 0.0, abs <= 0.0001);
+
+        let aabb = AABB::new(2.0, 4.0);
+        let point = aabb.support(
+            Transform::new(Vec2::zero(), FRAC_PI_4, 1.0),
+            Vec2::new(0.0, 1.0),
+        );
+        assert_float_eq!(point.y, 1.5 * f64::sqrt(2.0), abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_collides() {
+        let circ = Circle::new(1.0);
+        let aabb = AABB::new(2.0, 2.0);
+        let poly = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.5, 2.0),
+            Vec2::new(0.0, 1.0),
+        ])
+        .unwrap();
+
+        assert!(gjk_collides(
+            &circ,
+            Vec2::zero(),
+            &circ,
+            Vec2::new(1.9, 0.0)
+        ));
+        assert!(gjk_collides(&circ, Vec2::zero(), &circ, Vec2::zero()));
+        assert!(!gjk_collides(
+            &circ,
+            Vec2::zero(),
+            &circ,
+            Vec2::new(2.1, 0.0)
+        ));
+
+        assert!(gjk_collides(
+            &aabb,
+            Vec2::zero(),
+            &poly,
+            Vec2::new(0.9, 0.9)
+        ));
+        assert!(gjk_collides(
+            &aabb,
+            Vec2::zero(),
+            &poly,
+            Vec2::new(-0.5, -0.5)
+        ));
+        assert!(!gjk_collides(
+            &aabb,
+            Vec2::zero(),
+            &poly,
+            Vec2::new(1.1, 0.0)
+        ));
+        assert!(!gjk_collides(
+            &aabb,
+            Vec2::zero(),
+            &poly,
+            Vec2::new(0.0, -3.1)
+        ));
+
+        assert!(!gjk_collides(
+            &aabb,
+            Vec2::zero(),
+            &aabb,
+            Vec2::new(2.0, 0.0)
+        ));
+        assert!(!gjk_collides(
+            &circ,
+            Vec2::new(1.8, 1.8),
+            &aabb,
+            Vec2::zero()
+        ));
+        assert!(gjk_collides(
+            &aabb,
+            Transform::new(Vec2::zero(), FRAC_PI_4, 1.0),
+            &aabb,
+            Vec2::new(2.3, 0.0)
+        ));
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{
+    narrow::{
+        gjk::Supportable,
+        rounded::{rounded_feature, RoundedFeature},
+        sat::SATable,
+        shapes::Shapeable,
+    },
+    transform::Transform,
+    vec2::Vec2,
+    NumTolerance,
+};
+
+#[derive(Clone, Copy)]
+pub struct Contact<T: NumTolerance> {
+    pub point: Vec2<T>,
+    pub depth: T,
+}
+
+// `axis` points the same way as `Resolution::axis`, and there are always one or two contacts
+pub struct ContactManifold<T: NumTolerance> {
+    pub axis: Vec2<T>,
+    pub contacts: Vec<Contact<T>>,
+}
+
+pub fn contact_manifold<T, S, O>(
+    shape: &S,
+    transform: impl Into<Transform<T>>,
+    other: &O,
+    other_transform: impl Into<Transform<T>>,
+) -> Option<ContactManifold<T>>
+where
+    T: NumTolerance,
+    S: SATable<T> + Shapeable<T> + Supportable<T>,
+    O: SATable<T> + Shapeable<T> + Supportable<T>,
+{
+    let (transform, other_transform) = (transform.into(), other_transform.into());
+
+    // A concave shape touches through whichever of its pieces overlaps the other shape most deeply
+    if let Some(pieces) = shape.support_pieces() {
+        return deepest_manifold(
+            pieces
+                .iter()
+                .filter_map(|piece| contact_manifold(piece, transform, other, other_transform)),
+        );
+    }
+    if let Some(pieces) = other.support_pieces() {
+        return deepest_manifold(
+            pieces
+                .iter()
+                .filter_map(|piece| contact_manifold(shape, transform, piece, other_transform)),
+        );
+    }
+
+    let resolution = shape.collision_resolution(transform, other, other_transform);
+    if !resolution.colliding {
+        return None;
+    }
+
+    let axis = resolution.axis.normalized();
+    let features = (
+        rounded_feature(shape.shape(), transform),
+        rounded_feature(other.shape(), other_transform),
+    );
+
+    // A flat side has no single deepest point, so the rounder shape provides it
+    let radius = |feature: &Option<RoundedFeature<T>>| {
+        feature
+            .as_ref()
+            .map_or(T::infinity(), |feature| feature.radius)
+    };
+    let deepest = Contact {
+        point: match radius(&features.1) > radius(&features.0) {
+            true => other.support(other_transform, axis),
+            false => shape.support(transform, -axis),
+        },
+        depth: resolution.penetration,
+    };
+    let contacts = match features {
+        (Some(feature), Some(other_feature)) => clip_features(&feature, &other_feature, -axis),
+        _ => None,
+    };
+
+    Some(ContactManifold {
+        axis,
+        contacts: contacts.unwrap_or(vec![deepest]),
+    })
+}
+
+fn deepest_manifold<T: NumTolerance>(
+    manifolds: impl Iterator<Item = ContactManifold<T>>,
+) -> Option<ContactManifold<T>> {
+    let depth = |manifold: &ContactManifold<T>| {
+        manifold
+            .contacts
+            .iter()
+            .fold(T::min_value(), |depth, contact| {
+                T::max(depth, contact.depth)
+            })
+    };
+
+    manifolds.max_by(|manifold, other| depth(manifold).partial_cmp(&depth(other)).unwrap())
+}
+
+// Clips the incident edge against the side planes of the reference edge, the one facing the other shape most squarely
+fn clip_features<T: NumTolerance>(
+    feature: &RoundedFeature<T>,
+    other_feature: &RoundedFeature<T>,
+    normal: Vec2<T>,
+) -> Option<Vec<Contact<T>>> {
+    let edge = best_edge(&feature.vertices, normal)?;
+    let other_edge = best_edge(&other_feature.vertices, -normal)?;
+
+    let squareness = |(start, end): (Vec2<T>, Vec2<T>), direction: Vec2<T>| {
+        (end - start).normalized().dot(direction).abs()
+    };
+
+    let (reference, incident, direction) =
+        match squareness(edge, normal) <= squareness(other_edge, normal) {
+            true => (
+                (edge, feature.radius),
+                (other_edge, other_feature.radius),
+                normal,
+            ),
+            false => (
+                (other_edge, other_feature.radius),
+                (edge, feature.radius),
+                -normal,
+            ),
+        };
+    let ((ref_start, ref_end), ref_radius) = reference;
+    let ((inc_start, inc_end), inc_radius) = incident;
+
+    // A rounded corner is in contact rather than a face, so a single deepest point describes it
+    let ref_normal = towards((ref_end - ref_start).rotate_counter_90(), direction).normalized();
+    if !(T::one() - ref_normal.dot(direction)).is_trivial_abs() {
+        return None;
+    }
+
+    let tangent = (ref_end - ref_start).normalized();
+    let (inc_start, inc_end) = clip(
+        inc_start,
+        inc_end,
+        tangent,
+        tangent.dot(ref_start),
+        tangent.dot(ref_end),
+    )?;
+
+    let face = ref_normal.dot(ref_start) + ref_radius;
+    let contacts: Vec<Contact<T>> = [inc_start, inc_end]
+        .into_iter()
+        .map(|point| Contact {
+            point: point - ref_normal.scale(inc_radius),
+            depth: face - ref_normal.dot(point) + inc_radius,
+        })
+        .filter(|contact| T::zero().is_difference_small(contact.depth))
+        .collect();
+
+    match contacts.is_empty() {
+        true => None,
+        false => Some(contacts),
+    }
+}
+
+fn best_edge<T: NumTolerance>(
+    vertices: &[Vec2<T>],
+    direction: Vec2<T>,
+) -> Option<(Vec2<T>, Vec2<T>)> {
+    match vertices.len() {
+        0 | 1 => return None,
+        2 => return Some((vertices[0], vertices[1])),
+        _ => {}
+    }
+
+    let mut index = 0;
+    for (i, vertex) in vertices.iter().enumerate() {
+        if vertex.dot(direction) > vertices[index].dot(direction) {
+            index = i;
+        }
+    }
+
+    let vertex = vertices[index];
+    let prev = vertices[(index + vertices.len() - 1) % vertices.len()];
+    let next = vertices[(index + 1) % vertices.len()];
+
+    match (vertex - prev).normalized().dot(direction).abs()
+        <= (next - vertex).normalized().dot(direction).abs()
+    {
+        true => Some((prev, vertex)),
+        false => Some((vertex, next)),
+    }
+}
+
+// Keeps the part of the segment whose projection onto `tangent` lies within `min..max`
+fn clip<T: NumTolerance>(
+    start: Vec2<T>,
+    end: Vec2<T>,
+    tangent: Vec2<T>,
+    min: T,
+    max: T,
+) -> Option<(Vec2<T>, Vec2<T>)> {
+    let (start_proj, end_proj) = (tangent.dot(start), tangent.dot(end));
+    let (start, end, start_proj, end_proj) = match start_proj <= end_proj {
+        true => (start, end, start_proj, end_proj),
+        false => (end, start, end_proj, start_proj),
+    };
+
+    if end_proj < min || max < start_proj {
+        return None;
+    }
+
+    let along = |proj: T| match (end_proj - start_proj).is_zero() {
+        true => start,
+        false => start + (end - start).scale((proj - start_proj) / (end_proj - start_proj)),
+    };
+
+    Some((along(start_proj.max(min)), along(end_proj.min(max))))
+}
+
+fn towards<T: NumTolerance>(vector: Vec2<T>, target: Vec2<T>) -> Vec2<T> {
+    match vector.dot(target).is_sign_negative() {
+        true => -vector,
+        false => vector,
+    }
+}
+
+#[cfg(test)]
+mod manifold_tests {
+
+    use float_eq::assert_float_eq;
+
+    use super::contact_manifold;
+    use crate::{
+        narrow::shapes::{aabb::AABB, capsule::Capsule, circle::Circle},
+        transform::Transform,
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_stacked_boxes() {
+        let aabb = AABB::new(2.0, 2.0);
+
+        let manifold = contact_manifold(&aabb, Vec2::zero(), &aabb, Vec2::new(0.5, 1.9)).unwrap();
+        assert_float_eq!(manifold.axis.x, 0.0, abs <= 0.0001);
+        assert_float_eq!(manifold.axis.y, -1.0, abs <= 0.0001);
+        assert_eq!(manifold.contacts.len(), 2);
+
+        let mut xs: Vec<f64> = manifold.contacts.iter().map(|c| c.point.x).collect();
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_float_eq!(xs[0], -0.5, abs <= 0.0001);
+        assert_float_eq!(xs[1], 1.0, abs <= 0.0001);
+
+        for contact in manifold.contacts {
+            assert_float_eq!(contact.point.y, 0.9, abs <= 0.0001);
+            assert_float_eq!(contact.depth, 0.1, abs <= 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_tilted_box() {
+        let aabb = AABB::new(2.0, 2.0);
+        let tilted = Transform::new(Vec2::new(0.0, 2.0), 0.1, 1.0);
+
+        let manifold = contact_manifold(&aabb, Vec2::zero(), &aabb, tilted).unwrap();
+        assert_eq!(manifold.contacts.len(), 1);
+        assert_float_eq!(
+            manifold.contacts[0].depth,
+            f64::sin(0.1) + f64::cos(0.1) - 1.0,
+            abs <= 0.0001
+        );
+        assert_float_eq!(
+            manifold.contacts[0].point.x,
+            f64::sin(0.1) - f64::cos(0.1),
+            abs <= 0.0001
+        );
+    }
+
+    #[test]
+    fn test_rounded_shapes() {
+        let aabb = AABB::new(2.0, 2.0);
+        let circ = Circle::new(0.5);
+        let cap = Capsule::new(Vec2::new(1.0, 0.0), 0.5);
+
+        let manifold = contact_manifold(&circ, Vec2::new(0.3, 1.4), &aabb, Vec2::zero()).unwrap();
+        assert_eq!(manifold.contacts.len(), 1);
+        assert_float_eq!(manifold.contacts[0].point.x, 0.3, abs <= 0.0001);
+        assert_float_eq!(manifold.contacts[0].point.y, 0.9, abs <= 0.0001);
+        assert_float_eq!(manifold.contacts[0].depth, 0.1, abs <= 0.0001);
+
+        let manifold = contact_manifold(&aabb, Vec2::zero(), &cap, Vec2::new(0.0, 1.4)).unwrap();
+        assert_eq!(manifold.contacts.len(), 2);
+        for contact in manifold.contacts {
+            assert_float_eq!(f64::abs(contact.point.x), 1.0, abs <= 0.0001);
+            assert_float_eq!(contact.point.y, 0.9, abs <= 0.0001);
+            assert_float_eq!(contact.depth, 0.1, abs <= 0.0001);
+        }
+
+        let manifold = contact_manifold(
+            &cap,
+            Transform::new(Vec2::new(0.0, 1.45), 0.1, 1.0),
+            &aabb,
+            Vec2::zero(),
+        )
+        .unwrap();
+        assert_float_eq!(manifold.axis.y, 1.0, abs <= 0.0001);
+        assert_eq!(manifold.contacts.len(), 1);
+        assert_float_eq!(manifold.contacts[0].point.x, -f64::cos(0.1), abs <= 0.0001);
+        assert_float_eq!(
+            manifold.contacts[0].depth,
+            f64::sin(0.1) + 0.05,
+            abs <= 0.0001
+        );
+    }
+
+    #[test]
+    fn test_flat_against_round() {
+        let aabb = AABB::new(2.0, 2.0);
+        let circ = Circle::new(0.5);
+
+        let manifold = contact_manifold(&aabb, Vec2::zero(), &circ, Vec2::new(0.3, 1.4)).unwrap();
+        assert_eq!(manifold.contacts.len(), 1);
+        assert_float_eq!(manifold.axis.y, -1.0, abs <= 0.0001);
+        assert_float_eq!(manifold.contacts[0].point.x, 0.3, abs <= 0.0001);
+        assert_float_eq!(manifold.contacts[0].point.y, 0.9, abs <= 0.0001);
+        assert_float_eq!(manifold.contacts[0].depth, 0.1, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_separated() {
+        let aabb = AABB::new(2.0, 2.0);
+        let circ = Circle::new(0.5);
+
+        assert!(contact_manifold(&aabb, Vec2::zero(), &circ, Vec2::new(0.0, 1.6)).is_none());
+    }
+}
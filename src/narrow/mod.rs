@@ -1,2 +1,10 @@
+pub mod distance;
+pub mod dyn_shape;
+pub mod epa;
+pub mod gjk;
+pub mod manifold;
+pub mod ray;
+pub mod rounded;
 pub mod sat;
 pub mod shapes;
+pub mod toi;
@@ -0,0 +1,357 @@
+use crate::{transform::Transform, vec2::Vec2, NumTolerance};
+
+// `point` is `origin + direction * toi`, and rays starting inside a shape hit it at a `toi` of zero
+pub struct RayHit<T: NumTolerance> {
+    pub toi: T,
+    pub point: Vec2<T>,
+    pub normal: Vec2<T>,
+}
+
+pub trait Raycastable<T: NumTolerance> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>>;
+}
+
+// Casts the ray in the shape's local space, where `cast` returns the toi and local normal of the hit
+pub(crate) fn raycast_local<T: NumTolerance>(
+    transform: impl Into<Transform<T>>,
+    origin: Vec2<T>,
+    direction: Vec2<T>,
+    max_toi: T,
+    cast: impl Fn(Vec2<T>, Vec2<T>, T) -> Option<(T, Vec2<T>)>,
+) -> Option<RayHit<T>> {
+    let transform = transform.into();
+    let local_origin = transform.inverse_apply(origin);
+    let local_direction = transform
+        .inverse_rotate(direction)
+        .scale(T::one() / transform.scale());
+
+    let (toi, normal) = cast(local_origin, local_direction, max_toi)?;
+
+    Some(RayHit {
+        toi,
+        point: origin + direction.scale(toi),
+        normal: transform.rotate(normal),
+    })
+}
+
+pub(crate) fn raycast_circle<T: NumTolerance>(
+    center: Vec2<T>,
+    radius: T,
+    origin: Vec2<T>,
+    direction: Vec2<T>,
+    max_toi: T,
+) -> Option<(T, Vec2<T>)> {
+    let offset = origin - center;
+    let c = offset.length_squared() - radius * radius;
+    if c <= T::zero() {
+        return Some((T::zero(), inside_normal(direction)));
+    }
+
+    let a = direction.length_squared();
+    let b = offset.dot(direction);
+    let discriminant = b * b - a * c;
+    if a.is_zero() || discriminant.is_sign_negative() {
+        return None;
+    }
+
+    let toi = (-b - discriminant.sqrt()) / a;
+    if toi.is_sign_negative() || toi > max_toi {
+        return None;
+    }
+
+    Some((
+        toi,
+        (offset + direction.scale(toi)).scale(T::one() / radius),
+    ))
+}
+
+// Clips the ray against the outward half-plane of every edge of a convex polygon
+pub(crate) fn raycast_vertices<T: NumTolerance>(
+    vertices: &[Vec2<T>],
+    origin: Vec2<T>,
+    direction: Vec2<T>,
+    max_toi: T,
+) -> Option<(T, Vec2<T>)> {
+    let clockwise = (0..vertices.len())
+        .map(|i| vertices[i].cross(vertices[(i + 1) % vertices.len()]))
+        .fold(T::zero(), |area, cross| area + cross)
+        .is_sign_negative();
+
+    let (mut enter, mut exit) = (T::zero(), max_toi);
+    let mut normal = None;
+
+    for (i, &start) in vertices.iter().enumerate() {
+        let edge = vertices[(i + 1) % vertices.len()] - start;
+        let outward = match clockwise {
+            true => edge.rotate_counter_90(),
+            false => edge.rotate_clock_90(),
+        };
+
+        let distance = outward.dot(start - origin);
+        let speed = outward.dot(direction);
+
+        if speed.is_zero() {
+            if distance.is_sign_negative() {
+                return None;
+            }
+            continue;
+        }
+
+        let toi = distance / speed;
+        if speed.is_sign_negative() {
+            if toi > enter {
+                enter = toi;
+                normal = Some(outward.normalized());
+            }
+        } else if toi < exit {
+            exit = toi;
+        }
+
+        if enter > exit {
+            return None;
+        }
+    }
+
+    Some((enter, normal.unwrap_or(inside_normal(direction))))
+}
+
+pub(crate) fn inside_normal<T: NumTolerance>(direction: Vec2<T>) -> Vec2<T> {
+    match direction.length_squared().is_zero() {
+        true => Vec2::zero(),
+        false => -direction.normalized(),
+    }
+}
+
+#[cfg(test)]
+mod ray_tests {
+
+    use float_eq::assert_float_eq;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use super::Raycastable;
+    use crate::{
+        narrow::shapes::{
+            aabb::AABB, capsule::Capsule, circle::Circle, obb::OBB, p_gram::Pgram,
+            polygon::Polygon, triangle::Triangle,
+        },
+        transform::Transform,
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_aabb() {
+        let aabb = AABB::new(2.0, 4.0);
+
+        let hit = aabb
+            .raycast(
+                Vec2::new(1.0, 1.0),
+                Vec2::new(-3.0, 1.5),
+                Vec2::new(1.0, 0.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 3.0, abs <= 0.0001);
+        assert_float_eq!(hit.point.x, 0.0, abs <= 0.0001);
+        assert_float_eq!(hit.normal.x, -1.0, abs <= 0.0001);
+        assert_float_eq!(hit.normal.y, 0.0, abs <= 0.0001);
+
+        let hit = aabb
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(0.0, 5.0),
+                Vec2::new(0.0, -2.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 1.5, abs <= 0.0001);
+        assert_float_eq!(hit.normal.y, 1.0, abs <= 0.0001);
+
+        assert!(aabb
+            .raycast(Vec2::zero(), Vec2::new(-3.0, 0.0), Vec2::new(1.0, 0.0), 1.5)
+            .is_none());
+        assert!(aabb
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(-3.0, 2.5),
+                Vec2::new(1.0, 0.0),
+                10.0
+            )
+            .is_none());
+        assert!(aabb
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(-3.0, 0.0),
+                Vec2::new(-1.0, 0.0),
+                10.0
+            )
+            .is_none());
+
+        let hit = aabb
+            .raycast(Vec2::zero(), Vec2::new(0.5, 0.5), Vec2::new(1.0, 0.0), 10.0)
+            .unwrap();
+        assert_float_eq!(hit.toi, 0.0, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_rotated_aabb() {
+        let aabb = AABB::new(2.0, 2.0);
+        let diamond = Transform::new(Vec2::zero(), FRAC_PI_4, 1.0);
+
+        let hit = aabb
+            .raycast(diamond, Vec2::new(-3.0, 0.0), Vec2::new(1.0, 0.0), 10.0)
+            .unwrap();
+        assert_float_eq!(hit.toi, 3.0 - f64::sqrt(2.0), abs <= 0.0001);
+        assert_float_eq!(hit.normal.x, -f64::sqrt(0.5), abs <= 0.0001);
+        assert_float_eq!(f64::abs(hit.normal.y), f64::sqrt(0.5), abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_circle() {
+        let circ = Circle::new(1.0);
+
+        let hit = circ
+            .raycast(
+                Vec2::new(2.0, 0.0),
+                Vec2::new(2.0, 5.0),
+                Vec2::new(0.0, -1.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 4.0, abs <= 0.0001);
+        assert_float_eq!(hit.point.y, 1.0, abs <= 0.0001);
+        assert_float_eq!(hit.normal.y, 1.0, abs <= 0.0001);
+
+        let hit = circ
+            .raycast(
+                Transform::new(Vec2::zero(), 0.0, 2.0),
+                Vec2::new(-4.0, 0.0),
+                Vec2::new(1.0, 0.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 2.0, abs <= 0.0001);
+        assert_float_eq!(hit.normal.x, -1.0, abs <= 0.0001);
+
+        assert!(circ
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(-4.0, 1.1),
+                Vec2::new(1.0, 0.0),
+                10.0
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn test_capsule() {
+        let cap = Capsule::new(Vec2::new(0.0, 1.0), 0.5);
+
+        let hit = cap
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(-3.0, 0.5),
+                Vec2::new(1.0, 0.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 2.5, abs <= 0.0001);
+        assert_float_eq!(hit.normal.x, -1.0, abs <= 0.0001);
+
+        let hit = cap
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(0.0, 4.0),
+                Vec2::new(0.0, -1.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 2.5, abs <= 0.0001);
+        assert_float_eq!(hit.normal.y, 1.0, abs <= 0.0001);
+
+        assert!(cap
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(-3.0, 0.0),
+                Vec2::new(0.0, 1.0),
+                10.0
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn test_polygonal() {
+        let gram = Pgram::new(Vec2::new(2.0, 0.0), Vec2::new(1.0, 2.0));
+        let tri = Triangle::new(&[
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0),
+        ]);
+        let poly = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.5, 2.0),
+            Vec2::new(0.0, 1.0),
+        ])
+        .unwrap();
+        let upright = OBB::new(Vec2::new(2.0, 0.5), FRAC_PI_2);
+
+        let hit = tri
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(2.0, 2.0),
+                Vec2::new(-1.0, -1.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 1.5, abs <= 0.0001);
+        assert_float_eq!(hit.normal.x, f64::sqrt(0.5), abs <= 0.0001);
+
+        let hit = poly
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(0.25, 5.0),
+                Vec2::new(0.0, -1.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 3.5, abs <= 0.0001);
+        assert_float_eq!(hit.normal.x, -2.0 / f64::sqrt(5.0), abs <= 0.0001);
+
+        let hit = gram
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(-3.0, 0.0),
+                Vec2::new(1.0, 0.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 2.0, abs <= 0.0001);
+        assert_float_eq!(hit.normal.x, -2.0 / f64::sqrt(5.0), abs <= 0.0001);
+
+        let hit = upright
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(0.0, 5.0),
+                Vec2::new(0.0, -1.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 3.0, abs <= 0.0001);
+
+        assert!(tri
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(2.0, 0.0),
+                Vec2::new(-1.0, 2.0),
+                10.0
+            )
+            .is_none());
+    }
+}
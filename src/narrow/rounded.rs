@@ -0,0 +1,290 @@
+use crate::{
+    narrow::{gjk::Supportable, sat::Resolution, shapes::ShapeType},
+    transform::Transform,
+    utility::support_vertex,
+    vec2::Vec2,
+    NumTolerance,
+};
+
+// Every shape is treated as a convex outline in world space, inflated by a radius
+pub(crate) struct RoundedFeature<T: NumTolerance> {
+    pub(crate) vertices: Vec<Vec2<T>>,
+    pub(crate) radius: T,
+}
+
+// Shapes made of several convex pieces have no single feature
+pub(crate) fn rounded_feature<T: NumTolerance>(
+    shape: ShapeType<'_, T>,
+    transform: Transform<T>,
+) -> Option<RoundedFeature<T>> {
+    let (vertices, radius) = match shape {
+        ShapeType::AABB(aabb) => (aabb.vertices().to_vec(), T::zero()),
+        ShapeType::Capsule(capsule) => (
+            vec![capsule.half_path(), -capsule.half_path()],
+            capsule.radius(),
+        ),
+        ShapeType::Circle(circle) => (vec![Vec2::zero()], circle.radius()),
+        ShapeType::OBB(obb) => (obb.vertices().to_vec(), T::zero()),
+        ShapeType::Pgram(pgram) => (pgram.vertices().to_vec(), T::zero()),
+        ShapeType::Polygon(polygon) => (polygon.vertices().to_vec(), T::zero()),
+        ShapeType::RoundedPolygon(rounded) => (rounded.vertices().to_vec(), rounded.radius()),
+        ShapeType::Segment(segment) => (vec![segment.start(), segment.end()], T::zero()),
+        ShapeType::Triangle(triangle) => (triangle.vertices().to_vec(), T::zero()),
+        ShapeType::Chain(_)
+        | ShapeType::Compound(_)
+        | ShapeType::Concave(_)
+        | ShapeType::Ellipse(_)
+        | ShapeType::HalfPlane(_)
+        | ShapeType::None => return None,
+    };
+
+    Some(RoundedFeature {
+        vertices: vertices
+            .into_iter()
+            .map(|vertex| transform.apply(vertex))
+            .collect(),
+        radius: radius * transform.scale(),
+    })
+}
+
+// Lets curved shapes, which have no feature of their own, run GJK against the feature of any other shape
+impl<T: NumTolerance> Supportable<T> for RoundedFeature<T> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T> {
+        let transform = transform.into();
+        let vertex = support_vertex(transform, &self.vertices, direction);
+
+        let length = direction.length();
+        if length.is_trivial_abs() {
+            return vertex;
+        }
+
+        vertex + direction.scale(self.radius * transform.scale() / length)
+    }
+}
+
+// Resolves two convex cores, each a point, a segment or a polygon in world space, grown by a radius.
+// Separate cores are resolved along their closest points, overlapping cores along the axis of least overlap
+pub(crate) fn rounded_resolution<T: NumTolerance>(
+    core: &[Vec2<T>],
+    radius: T,
+    other_core: &[Vec2<T>],
+    other_radius: T,
+    resolve: bool,
+) -> Resolution<T> {
+    let mut resolution = Resolution::new();
+    let rad_sum = radius + other_radius;
+
+    if let Some((overlap, axis)) = core_overlap(core, other_core) {
+        resolution.colliding = true;
+
+        if resolve {
+            resolution.penetration = overlap + rad_sum;
+            resolution.axis = axis;
+        }
+
+        return resolution;
+    }
+
+    let (closest, other_closest) = edges(core)
+        .flat_map(|edge| {
+            edges(other_core).map(move |other_edge| closest_between_segments(edge, other_edge))
+        })
+        .min_by(|(a, b), (c, d)| {
+            (*a - *b)
+                .length_squared()
+                .partial_cmp(&(*c - *d).length_squared())
+                .unwrap()
+        })
+        .unwrap();
+
+    let separation = closest - other_closest;
+    let l_square = separation.length_squared();
+
+    if l_square.is_difference_small(rad_sum * rad_sum) {
+        resolution.colliding = true;
+
+        if resolve {
+            let length = l_square.sqrt();
+            resolution.penetration = rad_sum - length;
+            resolution.axis = match length.is_zero() {
+                true => Vec2::new(T::zero(), T::one()),
+                false => separation.scale(T::one() / length),
+            };
+        }
+    }
+
+    resolution
+}
+
+fn edges<T: NumTolerance>(core: &[Vec2<T>]) -> impl Iterator<Item = (Vec2<T>, Vec2<T>)> + '_ {
+    let count = match core.len() {
+        1 | 2 => 1,
+        len => len,
+    };
+
+    (0..count).map(move |i| (core[i], core[(i + 1) % core.len()]))
+}
+
+// SAT over the edge normals of both cores, plus the direction of any segment core, since a segment has no area to
+// separate it from something lying along its own line. Returns the smallest push of `core` and its direction
+fn core_overlap<T: NumTolerance>(core: &[Vec2<T>], other_core: &[Vec2<T>]) -> Option<(T, Vec2<T>)> {
+    let axes = [core, other_core].into_iter().flat_map(|vertices| {
+        edges(vertices)
+            .map(|(start, end)| end - start)
+            .filter(|edge| !edge.length_squared().is_zero())
+            .flat_map(move |edge| match vertices.len() {
+                2 => vec![edge.rotate_counter_90(), edge],
+                _ => vec![edge.rotate_counter_90()],
+            })
+    });
+
+    let mut best: Option<(T, Vec2<T>)> = None;
+
+    for axis in axes {
+        let axis = axis.normalized();
+        let (min, max) = extent(core, axis);
+        let (other_min, other_max) = extent(other_core, axis);
+
+        if max < other_min || other_max < min {
+            return None;
+        }
+
+        let (overlap, direction) = match other_max - min < max - other_min {
+            true => (other_max - min, axis),
+            false => (max - other_min, -axis),
+        };
+
+        if best.is_none_or(|(best_overlap, _)| overlap < best_overlap) {
+            best = Some((overlap, direction));
+        }
+    }
+
+    best
+}
+
+fn extent<T: NumTolerance>(vertices: &[Vec2<T>], axis: Vec2<T>) -> (T, T) {
+    vertices
+        .iter()
+        .map(|vertex| vertex.dot(axis))
+        .fold((T::max_value(), T::min_value()), |(min, max), proj| {
+            (min.min(proj), max.max(proj))
+        })
+}
+
+// The closest pair of points between two segments, either of which may be a single point
+pub(crate) fn closest_between_segments<T: NumTolerance>(
+    (start, end): (Vec2<T>, Vec2<T>),
+    (other_start, other_end): (Vec2<T>, Vec2<T>),
+) -> (Vec2<T>, Vec2<T>) {
+    let (dir, other_dir) = (end - start, other_end - other_start);
+    let offset = start - other_start;
+
+    let (length_sq, other_length_sq) = (dir.length_squared(), other_dir.length_squared());
+    let other_offset = other_dir.dot(offset);
+
+    let (s, t) = match (length_sq.is_zero(), other_length_sq.is_zero()) {
+        (true, true) => (T::zero(), T::zero()),
+        (true, false) => (
+            T::zero(),
+            num::clamp(other_offset / other_length_sq, T::zero(), T::one()),
+        ),
+        (false, true) => (
+            num::clamp(-dir.dot(offset) / length_sq, T::zero(), T::one()),
+            T::zero(),
+        ),
+        (false, false) => {
+            let c = dir.dot(offset);
+            let b = dir.dot(other_dir);
+            let denom = length_sq * other_length_sq - b * b;
+
+            // Parallel segments have no single closest pair, so any start point works
+            let s = match denom.is_zero() {
+                true => T::zero(),
+                false => num::clamp(
+                    (b * other_offset - c * other_length_sq) / denom,
+                    T::zero(),
+                    T::one(),
+                ),
+            };
+            let t = (b * s + other_offset) / other_length_sq;
+
+            match t {
+                _ if t < T::zero() => (num::clamp(-c / length_sq, T::zero(), T::one()), T::zero()),
+                _ if t > T::one() => (
+                    num::clamp((b - c) / length_sq, T::zero(), T::one()),
+                    T::one(),
+                ),
+                _ => (s, t),
+            }
+        }
+    };
+
+    (start + dir.scale(s), other_start + other_dir.scale(t))
+}
+
+#[cfg(test)]
+mod rounded_tests {
+
+    use float_eq::assert_float_eq;
+
+    use super::{closest_between_segments, rounded_resolution};
+    use crate::vec2::Vec2;
+
+    #[test]
+    fn test_closest_between_segments() {
+        let (a, b) = closest_between_segments(
+            (Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0)),
+            (Vec2::new(1.0, 1.0), Vec2::new(3.0, 3.0)),
+        );
+        assert_float_eq!(a.x, 1.0, abs <= 0.0001);
+        assert_float_eq!(a.y, 0.0, abs <= 0.0001);
+        assert_float_eq!(b.x, 1.0, abs <= 0.0001);
+        assert_float_eq!(b.y, 1.0, abs <= 0.0001);
+
+        let (a, b) = closest_between_segments(
+            (Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0)),
+            (Vec2::new(3.0, 1.0), Vec2::new(3.0, 1.0)),
+        );
+        assert_float_eq!(a.x, 2.0, abs <= 0.0001);
+        assert_float_eq!(b.x, 3.0, abs <= 0.0001);
+
+        // Parallel segments
+        let (a, b) = closest_between_segments(
+            (Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0)),
+            (Vec2::new(3.0, 1.0), Vec2::new(5.0, 1.0)),
+        );
+        assert_float_eq!(a.x, 2.0, abs <= 0.0001);
+        assert_float_eq!(b.x, 3.0, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_rounded_resolution() {
+        let segment = [Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0)];
+
+        // Crossing segments are pushed apart along the shorter way out
+        let res = rounded_resolution(
+            &segment,
+            0.5,
+            &[Vec2::new(0.8, -1.0), Vec2::new(0.8, 1.0)],
+            0.5,
+            true,
+        );
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 1.2, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, -1.0, abs <= 0.0001);
+
+        // Collinear segments only meet at their tips
+        let res = rounded_resolution(
+            &segment,
+            0.5,
+            &[Vec2::new(1.5, 0.0), Vec2::new(4.0, 0.0)],
+            0.5,
+            true,
+        );
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.5, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, -1.0, abs <= 0.0001);
+
+        assert!(!rounded_resolution(&segment, 0.5, &[Vec2::new(0.0, 1.1)], 0.5, true).colliding);
+    }
+}
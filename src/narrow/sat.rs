@@ -1,4 +1,14 @@
-use crate::{narrow::shapes::Shapeable, utility::Projection, vec2::Vec2, NumTolerance};
+use crate::{
+    broad::bounding_box::BoundingBox,
+    narrow::{
+        rounded::rounded_feature,
+        shapes::{polygon::Polygon, ShapeType, Shapeable},
+    },
+    transform::Transform,
+    utility::Projection,
+    vec2::Vec2,
+    NumTolerance,
+};
 
 pub enum Axis<T: NumTolerance> {
     Static { vector: Vec2<T>, normalized: bool },
@@ -6,32 +16,45 @@ pub enum Axis<T: NumTolerance> {
 }
 
 pub trait SATable<T: NumTolerance> {
-    fn axes(&self) -> impl Iterator<Item = Axis<T>>;
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>>;
 
-    fn project(&self, axis: Vec2<T>, position: Vec2<T>) -> Projection<T>;
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T>;
 
-    fn axis_from_point(&self, position: Vec2<T>, point: Vec2<T>) -> Vec2<T>;
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T>;
 
-    fn contains_point(&self, position: Vec2<T>, point: Vec2<T>) -> bool {
-        let axes = self.axes();
+    // Shapes that aren't convex hand back the convex pieces they are made of, which SAT then runs on one at a time
+    fn convex_pieces(&self) -> Option<&[Polygon<T>]> {
+        None
+    }
+
+    fn bounding_box(&self, transform: impl Into<Transform<T>>) -> BoundingBox<T> {
+        let transform = transform.into();
+        let x_proj = self.project(Vec2::new(T::one(), T::zero()), transform);
+        let y_proj = self.project(Vec2::new(T::zero(), T::one()), transform);
+
+        BoundingBox::new(
+            Vec2::new(x_proj.min, y_proj.min),
+            Vec2::new(x_proj.max, y_proj.max),
+        )
+    }
+
+    fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
+        let transform = transform.into();
+
+        let axes = self.axes(transform);
         for axis in axes {
             let axis_vector = match axis {
                 Axis::Static {
                     vector,
                     normalized: _normalized,
                 } => vector,
-                Axis::Dynamic { point: axis_point } => point - (position + axis_point),
+                Axis::Dynamic { point: axis_point } => point - axis_point,
             };
 
             if !axis_vector.length_squared().is_trivial_abs() {
-                let proj = self.project(axis_vector, position);
+                let proj = self.project(axis_vector, transform);
                 let point_proj = axis_vector.dot(point);
 
-                println!(
-                    "{}, {}, {}, {}, {}",
-                    axis_vector.x, axis_vector.y, proj.min, proj.max, point_proj
-                );
-
                 if point_proj.is_difference_small(proj.min)
                     || proj.max.is_difference_small(point_proj)
                 {
@@ -40,36 +63,55 @@ pub trait SATable<T: NumTolerance> {
             }
         }
 
-        return true;
+        true
     }
 
-    fn collides<S>(&self, position: Vec2<T>, shape: &S, shape_position: Vec2<T>) -> bool
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
     where
         S: SATable<T> + Shapeable<T>,
     {
-        self.sat_collides(position, shape, shape_position)
+        dispatch_collides(self, ShapeType::None, transform, shape, shape_transform)
     }
 
     fn collision_resolution<S>(
         &self,
-        position: Vec2<T>,
+        transform: impl Into<Transform<T>>,
         shape: &S,
-        shape_position: Vec2<T>,
+        shape_transform: impl Into<Transform<T>>,
     ) -> Resolution<T>
     where
         S: SATable<T> + Shapeable<T>,
     {
-        self.sat_collision_resolution(position, shape, shape_position)
+        dispatch_collision_resolution(self, ShapeType::None, transform, shape, shape_transform)
     }
 
     fn sat_collides(
         &self,
-        position: Vec2<T>,
+        transform: impl Into<Transform<T>>,
         shape: &impl SATable<T>,
-        shape_position: Vec2<T>,
+        shape_transform: impl Into<Transform<T>>,
     ) -> bool {
-        if !half_sat_resolution(self, position, shape, shape_position, false).colliding
-            || !half_sat_resolution(shape, shape_position, self, position, false).colliding
+        let transform = transform.into();
+        let shape_transform = shape_transform.into();
+
+        if let Some(pieces) = self.convex_pieces() {
+            return pieces
+                .iter()
+                .any(|piece| piece.sat_collides(transform, shape, shape_transform));
+        }
+        if let Some(pieces) = shape.convex_pieces() {
+            return pieces
+                .iter()
+                .any(|piece| self.sat_collides(transform, piece, shape_transform));
+        }
+
+        if !half_sat_resolution(self, transform, shape, shape_transform, false).colliding
+            || !half_sat_resolution(shape, shape_transform, self, transform, false).colliding
         {
             return false;
         }
@@ -79,17 +121,35 @@ pub trait SATable<T: NumTolerance> {
 
     fn sat_collision_resolution(
         &self,
-        position: Vec2<T>,
+        transform: impl Into<Transform<T>>,
         shape: &impl SATable<T>,
-        shape_position: Vec2<T>,
+        shape_transform: impl Into<Transform<T>>,
     ) -> Resolution<T> {
-        let resolution = half_sat_resolution(self, position, shape, shape_position, true);
+        let transform = transform.into();
+        let shape_transform = shape_transform.into();
+
+        if let Some(pieces) = self.convex_pieces() {
+            return deepest(
+                pieces
+                    .iter()
+                    .map(|piece| piece.sat_collision_resolution(transform, shape, shape_transform)),
+            );
+        }
+        if let Some(pieces) = shape.convex_pieces() {
+            return deepest(
+                pieces
+                    .iter()
+                    .map(|piece| self.sat_collision_resolution(transform, piece, shape_transform)),
+            );
+        }
+
+        let resolution = half_sat_resolution(self, transform, shape, shape_transform, true);
 
         if !resolution.colliding {
             return resolution;
         }
 
-        let flipped = half_sat_resolution(shape, shape_position, self, position, true).flipped();
+        let flipped = half_sat_resolution(shape, shape_transform, self, transform, true).flipped();
 
         if !flipped.colliding {
             return flipped;
@@ -124,25 +184,459 @@ impl<T: NumTolerance> Resolution<T> {
     }
 }
 
+impl<T: NumTolerance> Default for Resolution<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Every pair of shapes is routed here, so each combination has a single place that picks its algorithm.
+// Shapes made of several pieces are tested piece by piece, and pairs without a specialised path go through SAT
+pub(crate) fn dispatch_collides<T, S>(
+    actor: &(impl SATable<T> + ?Sized),
+    actor_type: ShapeType<'_, T>,
+    transform: impl Into<Transform<T>>,
+    shape: &S,
+    shape_transform: impl Into<Transform<T>>,
+) -> bool
+where
+    T: NumTolerance,
+    S: SATable<T> + Shapeable<T>,
+{
+    let transform = transform.into();
+    let shape_transform = shape_transform.into();
+
+    // The axis aligned paths only hold while neither shape is rotated or scaled
+    let translated = transform.is_translation() && shape_transform.is_translation();
+    let (position, shape_position) = (transform.translation, shape_transform.translation);
+
+    match (actor_type, shape.shape()) {
+        (ShapeType::HalfPlane(plane), ShapeType::HalfPlane(other)) => {
+            plane
+                .plane_resolution(transform, other, shape_transform, false)
+                .colliding
+        }
+        // A half-plane only needs how deep the other shape reaches along its normal, whatever that shape is
+        (ShapeType::HalfPlane(plane), _) => {
+            plane
+                .shape_resolution(transform, shape, shape_transform, false)
+                .colliding
+        }
+        (_, ShapeType::HalfPlane(plane)) => {
+            plane
+                .shape_resolution(shape_transform, actor, transform, false)
+                .colliding
+        }
+        (ShapeType::AABB(aabb), ShapeType::AABB(other)) if translated => {
+            aabb.aabb_resolution(position, other, shape_position, false)
+                .colliding
+        }
+        (ShapeType::AABB(aabb), ShapeType::Circle(circle)) if translated => {
+            circle
+                .aabb_resolution(shape_position, aabb, position, false)
+                .colliding
+        }
+        (ShapeType::Circle(circle), ShapeType::AABB(aabb)) if translated => {
+            circle
+                .aabb_resolution(position, aabb, shape_position, false)
+                .colliding
+        }
+        (ShapeType::Circle(circle), ShapeType::Circle(other)) if translated => {
+            circle
+                .circle_resolution(position, other, shape_position, false)
+                .colliding
+        }
+        (ShapeType::AABB(aabb), ShapeType::OBB(obb)) => {
+            obb.aabb_resolution(shape_transform, aabb, transform, false)
+                .colliding
+        }
+        (ShapeType::OBB(obb), ShapeType::AABB(aabb)) => {
+            obb.aabb_resolution(transform, aabb, shape_transform, false)
+                .colliding
+        }
+        (ShapeType::OBB(obb), ShapeType::OBB(other)) => {
+            obb.obb_resolution(transform, other, shape_transform, false)
+                .colliding
+        }
+        (ShapeType::Capsule(capsule), ShapeType::Circle(circle)) => {
+            capsule
+                .circle_resolution(transform, circle, shape_transform, false)
+                .colliding
+        }
+        (ShapeType::Circle(circle), ShapeType::Capsule(capsule)) => {
+            capsule
+                .circle_resolution(shape_transform, circle, transform, false)
+                .colliding
+        }
+        (ShapeType::Capsule(capsule), ShapeType::Capsule(other)) => {
+            capsule
+                .capsule_resolution(transform, other, shape_transform, false)
+                .colliding
+        }
+        (ShapeType::Capsule(capsule), ShapeType::AABB(aabb)) => {
+            capsule
+                .aabb_resolution(transform, aabb, shape_transform, false)
+                .colliding
+        }
+        (ShapeType::AABB(aabb), ShapeType::Capsule(capsule)) => {
+            capsule
+                .aabb_resolution(shape_transform, aabb, transform, false)
+                .colliding
+        }
+        (ShapeType::Circle(circle), ShapeType::Polygon(polygon)) => {
+            circle
+                .polygon_resolution(transform, polygon, shape_transform, false)
+                .colliding
+        }
+        (ShapeType::Polygon(polygon), ShapeType::Circle(circle)) => {
+            circle
+                .polygon_resolution(shape_transform, polygon, transform, false)
+                .colliding
+        }
+        (ShapeType::Circle(circle), ShapeType::Triangle(triangle)) => {
+            circle
+                .triangle_resolution(transform, triangle, shape_transform, false)
+                .colliding
+        }
+        (ShapeType::Triangle(triangle), ShapeType::Circle(circle)) => {
+            circle
+                .triangle_resolution(shape_transform, triangle, transform, false)
+                .colliding
+        }
+        (ShapeType::Circle(circle), ShapeType::Pgram(pgram)) => {
+            circle
+                .pgram_resolution(transform, pgram, shape_transform, false)
+                .colliding
+        }
+        (ShapeType::Pgram(pgram), ShapeType::Circle(circle)) => {
+            circle
+                .pgram_resolution(shape_transform, pgram, transform, false)
+                .colliding
+        }
+        (ShapeType::Ellipse(ellipse), ShapeType::Ellipse(other)) => {
+            ellipse
+                .ellipse_resolution(transform, other, shape_transform, false)
+                .colliding
+        }
+        (ShapeType::Segment(segment), ShapeType::Ellipse(ellipse)) => {
+            segment
+                .ellipse_resolution(transform, ellipse, shape_transform)
+                .colliding
+        }
+        (ShapeType::Ellipse(ellipse), ShapeType::Segment(segment)) => {
+            segment
+                .ellipse_resolution(shape_transform, ellipse, transform)
+                .colliding
+        }
+        (ShapeType::Compound(compound), _) => compound.children().iter().any(|(offset, child)| {
+            dispatch_collides(
+                child,
+                child.shape(),
+                transform.then(*offset),
+                shape,
+                shape_transform,
+            )
+        }),
+        (ShapeType::Concave(concave), _) => concave.pieces().iter().any(|piece| {
+            dispatch_collides(piece, piece.shape(), transform, shape, shape_transform)
+        }),
+        (actor_type, ShapeType::Compound(compound)) => {
+            compound.children().iter().any(|(offset, child)| {
+                dispatch_collides(
+                    actor,
+                    actor_type,
+                    transform,
+                    child,
+                    shape_transform.then(*offset),
+                )
+            })
+        }
+        (actor_type, ShapeType::Concave(concave)) => concave
+            .pieces()
+            .iter()
+            .any(|piece| dispatch_collides(actor, actor_type, transform, piece, shape_transform)),
+        (ShapeType::Chain(chain), _) => chain.segments().iter().any(|segment| {
+            dispatch_collides(segment, segment.shape(), transform, shape, shape_transform)
+        }),
+        (actor_type, ShapeType::Chain(chain)) => chain.segments().iter().any(|segment| {
+            dispatch_collides(actor, actor_type, transform, segment, shape_transform)
+        }),
+        (ShapeType::Segment(segment), shape_type) => {
+            match rounded_feature(shape_type, shape_transform) {
+                Some(feature) => segment.feature_resolution(transform, &feature).colliding,
+                None => actor.sat_collides(transform, shape, shape_transform),
+            }
+        }
+        (actor_type, ShapeType::Segment(segment)) => match rounded_feature(actor_type, transform) {
+            Some(feature) => {
+                segment
+                    .feature_resolution(shape_transform, &feature)
+                    .colliding
+            }
+            None => actor.sat_collides(transform, shape, shape_transform),
+        },
+        (ShapeType::Ellipse(ellipse), shape_type) => {
+            match rounded_feature(shape_type, shape_transform) {
+                Some(feature) => {
+                    ellipse
+                        .feature_resolution(transform, &feature, false)
+                        .colliding
+                }
+                None => {
+                    ellipse
+                        .projection_resolution(transform, shape, shape_transform, false)
+                        .colliding
+                }
+            }
+        }
+        (actor_type, ShapeType::Ellipse(ellipse)) => match rounded_feature(actor_type, transform) {
+            Some(feature) => {
+                ellipse
+                    .feature_resolution(shape_transform, &feature, false)
+                    .colliding
+            }
+            None => {
+                ellipse
+                    .projection_resolution(shape_transform, actor, transform, false)
+                    .colliding
+            }
+        },
+        (ShapeType::RoundedPolygon(rounded), shape_type) => {
+            match rounded_feature(shape_type, shape_transform) {
+                Some(feature) => {
+                    rounded
+                        .feature_resolution(transform, &feature, false)
+                        .colliding
+                }
+                None => actor.sat_collides(transform, shape, shape_transform),
+            }
+        }
+        (actor_type, ShapeType::RoundedPolygon(rounded)) => {
+            match rounded_feature(actor_type, transform) {
+                Some(feature) => {
+                    rounded
+                        .feature_resolution(shape_transform, &feature, false)
+                        .colliding
+                }
+                None => actor.sat_collides(transform, shape, shape_transform),
+            }
+        }
+        _ => actor.sat_collides(transform, shape, shape_transform),
+    }
+}
+
+pub(crate) fn dispatch_collision_resolution<T, S>(
+    actor: &(impl SATable<T> + ?Sized),
+    actor_type: ShapeType<'_, T>,
+    transform: impl Into<Transform<T>>,
+    shape: &S,
+    shape_transform: impl Into<Transform<T>>,
+) -> Resolution<T>
+where
+    T: NumTolerance,
+    S: SATable<T> + Shapeable<T>,
+{
+    let transform = transform.into();
+    let shape_transform = shape_transform.into();
+
+    let translated = transform.is_translation() && shape_transform.is_translation();
+    let (position, shape_position) = (transform.translation, shape_transform.translation);
+
+    match (actor_type, shape.shape()) {
+        (ShapeType::HalfPlane(plane), ShapeType::HalfPlane(other)) => {
+            plane.plane_resolution(transform, other, shape_transform, true)
+        }
+        (ShapeType::HalfPlane(plane), _) => {
+            plane.shape_resolution(transform, shape, shape_transform, true)
+        }
+        (_, ShapeType::HalfPlane(plane)) => plane
+            .shape_resolution(shape_transform, actor, transform, true)
+            .flipped(),
+        (ShapeType::AABB(aabb), ShapeType::AABB(other)) if translated => {
+            aabb.aabb_resolution(position, other, shape_position, true)
+        }
+        (ShapeType::AABB(aabb), ShapeType::Circle(circle)) if translated => circle
+            .aabb_resolution(shape_position, aabb, position, true)
+            .flipped(),
+        (ShapeType::Circle(circle), ShapeType::AABB(aabb)) if translated => {
+            circle.aabb_resolution(position, aabb, shape_position, true)
+        }
+        (ShapeType::Circle(circle), ShapeType::Circle(other)) if translated => {
+            circle.circle_resolution(position, other, shape_position, true)
+        }
+        (ShapeType::AABB(aabb), ShapeType::OBB(obb)) => obb
+            .aabb_resolution(shape_transform, aabb, transform, true)
+            .flipped(),
+        (ShapeType::OBB(obb), ShapeType::AABB(aabb)) => {
+            obb.aabb_resolution(transform, aabb, shape_transform, true)
+        }
+        (ShapeType::OBB(obb), ShapeType::OBB(other)) => {
+            obb.obb_resolution(transform, other, shape_transform, true)
+        }
+        (ShapeType::Capsule(capsule), ShapeType::Circle(circle)) => {
+            capsule.circle_resolution(transform, circle, shape_transform, true)
+        }
+        (ShapeType::Circle(circle), ShapeType::Capsule(capsule)) => capsule
+            .circle_resolution(shape_transform, circle, transform, true)
+            .flipped(),
+        (ShapeType::Capsule(capsule), ShapeType::Capsule(other)) => {
+            capsule.capsule_resolution(transform, other, shape_transform, true)
+        }
+        (ShapeType::Capsule(capsule), ShapeType::AABB(aabb)) => {
+            capsule.aabb_resolution(transform, aabb, shape_transform, true)
+        }
+        (ShapeType::AABB(aabb), ShapeType::Capsule(capsule)) => capsule
+            .aabb_resolution(shape_transform, aabb, transform, true)
+            .flipped(),
+        (ShapeType::Circle(circle), ShapeType::Polygon(polygon)) => {
+            circle.polygon_resolution(transform, polygon, shape_transform, true)
+        }
+        (ShapeType::Polygon(polygon), ShapeType::Circle(circle)) => circle
+            .polygon_resolution(shape_transform, polygon, transform, true)
+            .flipped(),
+        (ShapeType::Circle(circle), ShapeType::Triangle(triangle)) => {
+            circle.triangle_resolution(transform, triangle, shape_transform, true)
+        }
+        (ShapeType::Triangle(triangle), ShapeType::Circle(circle)) => circle
+            .triangle_resolution(shape_transform, triangle, transform, true)
+            .flipped(),
+        (ShapeType::Circle(circle), ShapeType::Pgram(pgram)) => {
+            circle.pgram_resolution(transform, pgram, shape_transform, true)
+        }
+        (ShapeType::Pgram(pgram), ShapeType::Circle(circle)) => circle
+            .pgram_resolution(shape_transform, pgram, transform, true)
+            .flipped(),
+        (ShapeType::Ellipse(ellipse), ShapeType::Ellipse(other)) => {
+            ellipse.ellipse_resolution(transform, other, shape_transform, true)
+        }
+        (ShapeType::Segment(segment), ShapeType::Ellipse(ellipse)) => {
+            segment.ellipse_resolution(transform, ellipse, shape_transform)
+        }
+        (ShapeType::Ellipse(ellipse), ShapeType::Segment(segment)) => segment
+            .ellipse_resolution(shape_transform, ellipse, transform)
+            .flipped(),
+        (ShapeType::Compound(compound), _) => {
+            deepest(compound.children().iter().map(|(offset, child)| {
+                dispatch_collision_resolution(
+                    child,
+                    child.shape(),
+                    transform.then(*offset),
+                    shape,
+                    shape_transform,
+                )
+            }))
+        }
+        (ShapeType::Concave(concave), _) => deepest(concave.pieces().iter().map(|piece| {
+            dispatch_collision_resolution(piece, piece.shape(), transform, shape, shape_transform)
+        })),
+        (actor_type, ShapeType::Compound(compound)) => {
+            deepest(compound.children().iter().map(|(offset, child)| {
+                dispatch_collision_resolution(
+                    actor,
+                    actor_type,
+                    transform,
+                    child,
+                    shape_transform.then(*offset),
+                )
+            }))
+        }
+        (actor_type, ShapeType::Concave(concave)) => {
+            deepest(concave.pieces().iter().map(|piece| {
+                dispatch_collision_resolution(actor, actor_type, transform, piece, shape_transform)
+            }))
+        }
+        (ShapeType::Chain(chain), _) => deepest(chain.segments().iter().map(|segment| {
+            dispatch_collision_resolution(
+                segment,
+                segment.shape(),
+                transform,
+                shape,
+                shape_transform,
+            )
+        })),
+        (actor_type, ShapeType::Chain(chain)) => deepest(chain.segments().iter().map(|segment| {
+            dispatch_collision_resolution(actor, actor_type, transform, segment, shape_transform)
+        })),
+        // Segments resolve exactly against anything with a single rounded outline
+        (ShapeType::Segment(segment), shape_type) => {
+            match rounded_feature(shape_type, shape_transform) {
+                Some(feature) => segment.feature_resolution(transform, &feature),
+                None => actor.sat_collision_resolution(transform, shape, shape_transform),
+            }
+        }
+        (actor_type, ShapeType::Segment(segment)) => match rounded_feature(actor_type, transform) {
+            Some(feature) => segment
+                .feature_resolution(shape_transform, &feature)
+                .flipped(),
+            None => actor.sat_collision_resolution(transform, shape, shape_transform),
+        },
+        // Ellipses have no finite axes, so they go through support mappings, or a projection search if there are none
+        (ShapeType::Ellipse(ellipse), shape_type) => {
+            match rounded_feature(shape_type, shape_transform) {
+                Some(feature) => ellipse.feature_resolution(transform, &feature, true),
+                None => ellipse.projection_resolution(transform, shape, shape_transform, true),
+            }
+        }
+        (actor_type, ShapeType::Ellipse(ellipse)) => match rounded_feature(actor_type, transform) {
+            Some(feature) => ellipse
+                .feature_resolution(shape_transform, &feature, true)
+                .flipped(),
+            None => ellipse
+                .projection_resolution(shape_transform, actor, transform, true)
+                .flipped(),
+        },
+        (ShapeType::RoundedPolygon(rounded), shape_type) => {
+            match rounded_feature(shape_type, shape_transform) {
+                Some(feature) => rounded.feature_resolution(transform, &feature, true),
+                None => actor.sat_collision_resolution(transform, shape, shape_transform),
+            }
+        }
+        (actor_type, ShapeType::RoundedPolygon(rounded)) => {
+            match rounded_feature(actor_type, transform) {
+                Some(feature) => rounded
+                    .feature_resolution(shape_transform, &feature, true)
+                    .flipped(),
+                None => actor.sat_collision_resolution(transform, shape, shape_transform),
+            }
+        }
+        _ => actor.sat_collision_resolution(transform, shape, shape_transform),
+    }
+}
+
+// The deepest overlapping piece decides how a multi-piece shape is pushed out
+pub(crate) fn deepest<T: NumTolerance>(
+    resolutions: impl Iterator<Item = Resolution<T>>,
+) -> Resolution<T> {
+    resolutions.filter(|resolution| resolution.colliding).fold(
+        Resolution::new(),
+        |deepest, resolution| match !deepest.colliding
+            || resolution.penetration > deepest.penetration
+        {
+            true => resolution,
+            false => deepest,
+        },
+    )
+}
+
 fn half_sat_resolution<T>(
     actor: &(impl SATable<T> + ?Sized),
-    actor_position: Vec2<T>,
+    actor_transform: Transform<T>,
     pushed: &(impl SATable<T> + ?Sized),
-    pushed_position: Vec2<T>,
+    pushed_transform: Transform<T>,
     accurate: bool,
 ) -> Resolution<T>
 where
     T: NumTolerance,
 {
     let mut resolution = Resolution::new();
-    let direction = actor_position - pushed_position;
+    let direction = actor_transform.translation - pushed_transform.translation;
 
-    let axes = actor.axes();
+    let axes = actor.axes(actor_transform);
     for axis in axes {
-        let axis_vector = generate_axis(axis, actor_position, pushed, pushed_position, accurate);
+        let axis_vector = generate_axis(axis, pushed, pushed_transform, accurate);
 
-        let actor_proj = actor.project(axis_vector, actor_position);
-        let pushed_proj = pushed.project(axis_vector, pushed_position);
+        let actor_proj = actor.project(axis_vector, actor_transform);
+        let pushed_proj = pushed.project(axis_vector, pushed_transform);
 
         if actor_proj.max.is_difference_small(pushed_proj.min)
             || pushed_proj.max.is_difference_small(actor_proj.min)
@@ -170,9 +664,8 @@ where
 
 fn generate_axis<T>(
     raw: Axis<T>,
-    actor_position: Vec2<T>,
     pushed: &(impl SATable<T> + ?Sized),
-    pushed_position: Vec2<T>,
+    pushed_transform: Transform<T>,
     accurate: bool,
 ) -> Vec2<T>
 where
@@ -187,7 +680,7 @@ where
             false => vector,
         },
         Axis::Dynamic { point } => {
-            let vector = pushed.axis_from_point(pushed_position, actor_position + point);
+            let vector = pushed.axis_from_point(pushed_transform, point);
 
             match accurate {
                 true => vector.normalized(),
@@ -1,9 +1,12 @@
 use crate::{
     narrow::{
-        sat::{Axis, Resolution, SATable},
-        shapes::{ShapeType, Shapeable},
+        gjk::Supportable,
+        ray::{raycast_local, raycast_vertices, RayHit, Raycastable},
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+        shapes::{check_size, ShapeError, ShapeType, Shapeable},
     },
-    utility::{closest_vertex, project_onto, Projection},
+    transform::Transform,
+    utility::{closest_vertex, project_onto, support_vertex, Projection},
     vec2::Vec2,
     NumTolerance,
 };
@@ -13,11 +16,62 @@ pub struct AABB<T: NumTolerance> {
     height: T,
 }
 
+// Entry and exit are fractions of the velocity, so a hit within the current step has an entry in `0..=1`
+pub struct SweepResolution<T: NumTolerance> {
+    pub colliding: bool,
+    pub entry: T,
+    pub exit: T,
+    pub normal: Vec2<T>,
+}
+
+impl<T: NumTolerance> SweepResolution<T> {
+    pub fn new() -> Self {
+        Self {
+            colliding: false,
+            entry: T::one(),
+            exit: T::one(),
+            normal: Vec2::zero(),
+        }
+    }
+
+    // The velocity left after the hit, with the part pushing into the surface removed; a miss keeps all of it
+    pub fn slide_velocity(&self, velocity: Vec2<T>) -> Vec2<T> {
+        if !self.colliding {
+            return velocity;
+        }
+
+        let remaining = velocity.scale(T::one() - self.entry);
+
+        remaining - self.normal.scale(remaining.dot(self.normal))
+    }
+}
+
+impl<T: NumTolerance> Default for SweepResolution<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: NumTolerance> AABB<T> {
     pub fn new(width: T, height: T) -> Self {
         Self { width, height }
     }
 
+    pub fn try_new(width: T, height: T) -> Result<Self, ShapeError> {
+        check_size(width)?;
+        check_size(height)?;
+
+        Ok(Self::new(width, height))
+    }
+
+    pub fn width(&self) -> T {
+        self.width
+    }
+
+    pub fn height(&self) -> T {
+        self.height
+    }
+
     pub fn vertices(&self) -> [Vec2<T>; 4] {
         let two = T::one() + T::one();
 
@@ -90,80 +144,178 @@ impl<T: NumTolerance> AABB<T> {
 
         resolution
     }
+
+    pub fn aabb_sweep(
+        &self,
+        position: Vec2<T>,
+        velocity: Vec2<T>,
+        shape: &AABB<T>,
+        shape_position: Vec2<T>,
+    ) -> SweepResolution<T> {
+        let mut resolution = SweepResolution::new();
+        let two = T::one() + T::one();
+
+        let x_times = sweep_axis(
+            position.x - self.width / two,
+            position.x + self.width / two,
+            shape_position.x - shape.width / two,
+            shape_position.x + shape.width / two,
+            velocity.x,
+        );
+        let y_times = sweep_axis(
+            position.y - self.height / two,
+            position.y + self.height / two,
+            shape_position.y - shape.height / two,
+            shape_position.y + shape.height / two,
+            velocity.y,
+        );
+
+        let ((x_entry, x_exit), (y_entry, y_exit)) = match (x_times, y_times) {
+            (Some(x_times), Some(y_times)) => (x_times, y_times),
+            _ => return resolution,
+        };
+
+        let entry = T::max(x_entry, y_entry);
+        let exit = T::min(x_exit, y_exit);
+
+        // Boxes already overlapping at the start are left to `aabb_resolution`
+        if exit.is_difference_small(entry)
+            || (entry.is_sign_negative() && !entry.is_trivial_abs())
+            || entry > T::one()
+        {
+            return resolution;
+        }
+
+        resolution.colliding = true;
+        resolution.entry = T::max(entry, T::zero());
+        resolution.exit = exit;
+        resolution.normal = match x_entry > y_entry {
+            true => Vec2::new(-velocity.x.signum(), T::zero()),
+            false => Vec2::new(T::zero(), -velocity.y.signum()),
+        };
+
+        resolution
+    }
+}
+
+// Times at which the moving interval starts and stops overlapping the static one
+fn sweep_axis<T: NumTolerance>(
+    self_min: T,
+    self_max: T,
+    shape_min: T,
+    shape_max: T,
+    velocity: T,
+) -> Option<(T, T)> {
+    if velocity.is_zero() {
+        return match self_max.is_difference_small(shape_min)
+            || shape_max.is_difference_small(self_min)
+        {
+            true => None,
+            false => Some((T::neg_infinity(), T::infinity())),
+        };
+    }
+
+    match velocity.is_sign_positive() {
+        true => Some((
+            (shape_min - self_max) / velocity,
+            (shape_max - self_min) / velocity,
+        )),
+        false => Some((
+            (shape_max - self_min) / velocity,
+            (shape_min - self_max) / velocity,
+        )),
+    }
 }
 
 impl<T: NumTolerance> SATable<T> for AABB<T> {
-    fn axes(&self) -> impl Iterator<Item = Axis<T>> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        let transform = transform.into();
+
         [
             Axis::Static {
-                vector: Vec2::new(T::one(), T::zero()),
+                vector: transform.rotate(Vec2::new(T::one(), T::zero())),
                 normalized: true,
             },
             Axis::Static {
-                vector: Vec2::new(T::zero(), T::one()),
+                vector: transform.rotate(Vec2::new(T::zero(), T::one())),
                 normalized: true,
             },
         ]
         .into_iter()
     }
 
-    fn project(&self, axis: Vec2<T>, position: Vec2<T>) -> Projection<T> {
-        project_onto(position, &self.vertices(), axis)
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        project_onto(transform, &self.vertices(), axis)
     }
 
-    fn axis_from_point(&self, position: Vec2<T>, point: Vec2<T>) -> Vec2<T> {
-        closest_vertex(point, position, &self.vertices()) - point
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        closest_vertex(point, transform, &self.vertices()) - point
     }
 
-    fn contains_point(&self, position: Vec2<T>, point: Vec2<T>) -> bool {
+    fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
         let two = T::one() + T::one();
+        let local = transform.into().inverse_apply(point);
 
-        if (position.x - point.x).abs() > self.width / two {
+        if local.x.abs() > self.width / two {
             return false;
         }
 
-        (position.y - point.y).abs() <= self.height / two
+        local.y.abs() <= self.height / two
     }
 
-    fn collides<S>(&self, position: Vec2<T>, shape: &S, shape_position: Vec2<T>) -> bool
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
     where
         S: SATable<T> + Shapeable<T>,
     {
-        match shape.shape() {
-            ShapeType::AABB(aabb) => {
-                self.aabb_resolution(position, aabb, shape_position, false)
-                    .colliding
-            }
-            ShapeType::Circle(circle) => {
-                circle
-                    .aabb_resolution(shape_position, self, position, false)
-                    .colliding
-            }
-            _ => self.sat_collides(position, shape, shape_position),
-        }
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
     }
 
     fn collision_resolution<S>(
         &self,
-        position: Vec2<T>,
+        transform: impl Into<Transform<T>>,
         shape: &S,
-        shape_position: Vec2<T>,
+        shape_transform: impl Into<Transform<T>>,
     ) -> Resolution<T>
     where
         S: SATable<T> + Shapeable<T>,
     {
-        match shape.shape() {
-            ShapeType::AABB(aabb) => self.aabb_resolution(position, aabb, shape_position, true),
-            ShapeType::Circle(circle) => circle
-                .aabb_resolution(shape_position, self, position, true)
-                .flipped(),
-            _ => self.sat_collision_resolution(position, shape, shape_position),
-        }
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Supportable<T> for AABB<T> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T> {
+        support_vertex(transform, &self.vertices(), direction)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for AABB<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        raycast_local(
+            transform,
+            origin,
+            direction,
+            max_toi,
+            |origin, direction, max_toi| {
+                raycast_vertices(&self.vertices(), origin, direction, max_toi)
+            },
+        )
     }
 }
 
 impl<T: NumTolerance> Shapeable<T> for AABB<T> {
-    fn shape(&self) -> ShapeType<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
         ShapeType::AABB(self)
     }
 }
@@ -173,7 +325,7 @@ mod aabb_tests {
 
     use float_eq::assert_float_eq;
 
-    use super::AABB;
+    use super::{ShapeError, AABB};
     use crate::{narrow::sat::SATable, vec2::Vec2};
 
     #[test]
@@ -202,6 +354,103 @@ mod aabb_tests {
         assert_float_eq!(vertices[3].y, 2.5, abs <= 0.01);
     }
 
+    #[test]
+    fn test_sweep() {
+        let player = AABB::new(1.0, 2.0);
+        let floor = AABB::new(10.0, 1.0);
+        let wall = AABB::new(1.0, 10.0);
+
+        let sweep = player.aabb_sweep(
+            Vec2::new(0.0, 3.0),
+            Vec2::new(1.0, -4.0),
+            &floor,
+            Vec2::zero(),
+        );
+        assert!(sweep.colliding);
+        assert_float_eq!(sweep.entry, 0.375, abs <= 0.0001);
+        assert_float_eq!(sweep.exit, 1.125, abs <= 0.0001);
+        assert_float_eq!(sweep.normal.x, 0.0, abs <= 0.0001);
+        assert_float_eq!(sweep.normal.y, 1.0, abs <= 0.0001);
+
+        let slide = sweep.slide_velocity(Vec2::new(1.0, -4.0));
+        assert_float_eq!(slide.x, 0.625, abs <= 0.0001);
+        assert_float_eq!(slide.y, 0.0, abs <= 0.0001);
+
+        let sweep = player.aabb_sweep(
+            Vec2::new(-3.0, 0.0),
+            Vec2::new(4.0, 1.0),
+            &wall,
+            Vec2::zero(),
+        );
+        assert!(sweep.colliding);
+        assert_float_eq!(sweep.entry, 0.5, abs <= 0.0001);
+        assert_float_eq!(sweep.normal.x, -1.0, abs <= 0.0001);
+
+        let slide = sweep.slide_velocity(Vec2::new(4.0, 1.0));
+        assert_float_eq!(slide.x, 0.0, abs <= 0.0001);
+        assert_float_eq!(slide.y, 0.5, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_sweep_miss() {
+        let player = AABB::new(1.0, 2.0);
+        let floor = AABB::new(10.0, 1.0);
+
+        // Resting on the floor while walking along it
+        let sweep = player.aabb_sweep(
+            Vec2::new(0.0, 1.5),
+            Vec2::new(2.0, 0.0),
+            &floor,
+            Vec2::zero(),
+        );
+        assert!(!sweep.colliding);
+        assert_float_eq!(
+            sweep.slide_velocity(Vec2::new(2.0, 0.0)).x,
+            2.0,
+            abs <= 0.0001
+        );
+
+        let sweep = player.aabb_sweep(
+            Vec2::new(0.0, 1.5),
+            Vec2::new(0.0, -0.5),
+            &floor,
+            Vec2::zero(),
+        );
+        assert!(sweep.colliding);
+        assert_float_eq!(sweep.entry, 0.0, abs <= 0.0001);
+
+        assert!(
+            !player
+                .aabb_sweep(
+                    Vec2::new(0.0, 5.0),
+                    Vec2::new(0.0, -1.0),
+                    &floor,
+                    Vec2::zero()
+                )
+                .colliding
+        );
+        assert!(
+            !player
+                .aabb_sweep(
+                    Vec2::new(0.0, 3.0),
+                    Vec2::new(0.0, 1.0),
+                    &floor,
+                    Vec2::zero()
+                )
+                .colliding
+        );
+        assert!(
+            !player
+                .aabb_sweep(
+                    Vec2::new(0.0, 0.5),
+                    Vec2::new(1.0, 0.0),
+                    &floor,
+                    Vec2::zero()
+                )
+                .colliding
+        );
+    }
+
     #[test]
     fn test_contains_point() {
         let box0 = AABB::new(8.0, 4.0);
@@ -217,4 +466,18 @@ mod aabb_tests {
         assert!(!box1.contains_point(Vec2::new(20.0, 20.0), Vec2::new(-1.0, 0.5)));
         assert!(!box1.contains_point(Vec2::new(0.0, 0.0), Vec2::new(6.0, 1.0)));
     }
+
+    #[test]
+    fn test_try_new() {
+        assert!(AABB::try_new(2.0, 1.0).is_ok());
+        assert!(matches!(AABB::try_new(0.0, 1.0), Err(ShapeError::ZeroSize)));
+        assert!(matches!(
+            AABB::try_new(2.0, -1.0),
+            Err(ShapeError::NegativeSize)
+        ));
+        assert!(matches!(
+            AABB::try_new(f64::NEG_INFINITY, 1.0),
+            Err(ShapeError::NonFinite)
+        ));
+    }
 }
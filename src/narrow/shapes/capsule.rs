@@ -1,11 +1,19 @@
 use crate::{
-    narrow::sat::{Axis, Resolution, SATable},
+    narrow::{
+        gjk::Supportable,
+        ray::{raycast_circle, raycast_local, raycast_vertices, RayHit, Raycastable},
+        rounded::rounded_resolution,
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+    },
+    transform::Transform,
     utility::{closest_vertex, Projection},
     vec2::Vec2,
     NumTolerance,
 };
 
-use super::{ShapeType, Shapeable};
+use super::{
+    aabb::AABB, check_finite, check_size, circle::Circle, ShapeError, ShapeType, Shapeable,
+};
 
 pub struct Capsule<T: NumTolerance> {
     half_path: Vec2<T>,
@@ -16,66 +24,219 @@ impl<T: NumTolerance> Capsule<T> {
     pub fn new(half_path: Vec2<T>, radius: T) -> Self {
         Self { half_path, radius }
     }
+
+    // A capsule without a path would have no direction for its side axes
+    pub fn try_new(half_path: Vec2<T>, radius: T) -> Result<Self, ShapeError> {
+        check_finite(&[half_path])?;
+        check_size(radius)?;
+
+        if half_path.length_squared().is_zero() {
+            return Err(ShapeError::DegenerateGeometry);
+        }
+
+        Ok(Self::new(half_path, radius))
+    }
+
+    pub fn half_path(&self) -> Vec2<T> {
+        self.half_path
+    }
+
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    pub fn circle_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &Circle<T>,
+        shape_transform: impl Into<Transform<T>>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        let transform = transform.into();
+        let shape_transform = shape_transform.into();
+
+        rounded_resolution(
+            &self.segment(transform),
+            self.radius * transform.scale(),
+            &[shape_transform.translation],
+            shape.radius() * shape_transform.scale(),
+            resolve,
+        )
+    }
+
+    pub fn capsule_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &Capsule<T>,
+        shape_transform: impl Into<Transform<T>>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        let transform = transform.into();
+        let shape_transform = shape_transform.into();
+
+        rounded_resolution(
+            &self.segment(transform),
+            self.radius * transform.scale(),
+            &shape.segment(shape_transform),
+            shape.radius * shape_transform.scale(),
+            resolve,
+        )
+    }
+
+    pub fn aabb_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &AABB<T>,
+        shape_transform: impl Into<Transform<T>>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        let transform = transform.into();
+        let shape_transform = shape_transform.into();
+
+        rounded_resolution(
+            &self.segment(transform),
+            self.radius * transform.scale(),
+            &shape.vertices().map(|vertex| shape_transform.apply(vertex)),
+            T::zero(),
+            resolve,
+        )
+    }
+
+    // The world space path the capsule's radius is swept along
+    fn segment(&self, transform: Transform<T>) -> [Vec2<T>; 2] {
+        let half_path = transform.apply_vector(self.half_path);
+
+        [
+            transform.translation + half_path,
+            transform.translation - half_path,
+        ]
+    }
 }
 
 impl<T: NumTolerance> SATable<T> for Capsule<T> {
-    fn axes(&self) -> impl Iterator<Item = Axis<T>> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        let transform = transform.into();
+        let half_path = transform.apply_vector(self.half_path);
+
         [
             Axis::Static {
-                vector: self.half_path.rotate_counter_90(),
+                vector: half_path.rotate_counter_90(),
                 normalized: false,
             },
             Axis::Dynamic {
-                point: self.half_path,
+                point: transform.translation + half_path,
             },
             Axis::Dynamic {
-                point: -self.half_path,
+                point: transform.translation - half_path,
             },
         ]
         .into_iter()
     }
 
-    fn project(&self, axis: Vec2<T>, position: Vec2<T>) -> Projection<T> {
-        let dot = axis.dot(position);
-        let path_proj = axis.dot(self.half_path).abs();
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        let transform = transform.into();
+        let dot = axis.dot(transform.translation);
+        let path_proj = axis.dot(transform.apply_vector(self.half_path)).abs();
 
-        let proj = self.radius * axis.length(); // The max and min will occur with parallel vectors, so the dot product is the product of the lengths
+        let proj = self.radius * transform.scale() * axis.length(); // The max and min will occur with parallel vectors, so the dot product is the product of the lengths
         Projection {
             min: dot - path_proj - proj,
             max: dot + path_proj + proj,
         }
     }
 
-    fn axis_from_point(&self, position: Vec2<T>, point: Vec2<T>) -> Vec2<T> {
-        closest_vertex(point, position, &[self.half_path, -self.half_path]) - point
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        closest_vertex(point, transform, &[self.half_path, -self.half_path]) - point
     }
 
-    fn collides<S>(&self, position: Vec2<T>, shape: &S, shape_position: Vec2<T>) -> bool
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
     where
         S: SATable<T> + Shapeable<T>,
     {
-        match shape.shape() {
-            _ => self.sat_collides(position, shape, shape_position),
-        }
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
     }
 
     fn collision_resolution<S>(
         &self,
-        position: Vec2<T>,
+        transform: impl Into<Transform<T>>,
         shape: &S,
-        shape_position: Vec2<T>,
+        shape_transform: impl Into<Transform<T>>,
     ) -> Resolution<T>
     where
         S: SATable<T> + Shapeable<T>,
     {
-        match shape.shape() {
-            _ => self.sat_collision_resolution(position, shape, shape_position),
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Supportable<T> for Capsule<T> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T> {
+        let transform = transform.into();
+        let half_path = transform.apply_vector(self.half_path);
+        let end = match half_path.dot(direction).is_sign_negative() {
+            true => transform.translation - half_path,
+            false => transform.translation + half_path,
+        };
+
+        let length = direction.length();
+        if length.is_trivial_abs() {
+            return end;
         }
+
+        end + direction.scale(self.radius * transform.scale() / length)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for Capsule<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        raycast_local(
+            transform,
+            origin,
+            direction,
+            max_toi,
+            |origin, direction, max_toi| {
+                let side = match self.half_path.length_squared().is_zero() {
+                    true => Vec2::zero(),
+                    false => self
+                        .half_path
+                        .rotate_counter_90()
+                        .normalized()
+                        .scale(self.radius),
+                };
+                let body = [
+                    -self.half_path - side,
+                    self.half_path - side,
+                    self.half_path + side,
+                    side - self.half_path,
+                ];
+
+                // The capsule is the union of its straight body and the two end caps
+                [
+                    raycast_vertices(&body, origin, direction, max_toi),
+                    raycast_circle(self.half_path, self.radius, origin, direction, max_toi),
+                    raycast_circle(-self.half_path, self.radius, origin, direction, max_toi),
+                ]
+                .into_iter()
+                .flatten()
+                .min_by(|(toi, _), (other_toi, _)| toi.partial_cmp(other_toi).unwrap())
+            },
+        )
     }
 }
 
 impl<T: NumTolerance> Shapeable<T> for Capsule<T> {
-    fn shape(&self) -> ShapeType<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
         ShapeType::Capsule(self)
     }
 }
@@ -83,9 +244,16 @@ impl<T: NumTolerance> Shapeable<T> for Capsule<T> {
 #[cfg(test)]
 mod circle_tests {
 
-    use super::Capsule;
+    use float_eq::assert_float_eq;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use super::{Capsule, ShapeError};
     use crate::{
-        narrow::sat::{Axis, SATable},
+        narrow::{
+            sat::{Axis, SATable},
+            shapes::{aabb::AABB, circle::Circle},
+        },
+        transform::Transform,
         vec2::Vec2,
     };
 
@@ -97,7 +265,7 @@ mod circle_tests {
         let pos0 = Vec2::new(3.0, 0.0);
         let pos1 = Vec2::new(-1.0, -1.0);
 
-        for axis in cap0.axes() {
+        for axis in cap0.axes(Vec2::zero()) {
             match axis {
                 Axis::Dynamic { point: _point } => {
                     let ax = cap0.axis_from_point(Vec2::zero(), pos0);
@@ -110,7 +278,7 @@ mod circle_tests {
             }
         }
 
-        for axis in cap1.axes() {
+        for axis in cap1.axes(Vec2::zero()) {
             match axis {
                 Axis::Dynamic { point: _point } => {
                     let ax = cap1.axis_from_point(pos0, pos1);
@@ -141,4 +309,85 @@ mod circle_tests {
         assert!(cap1.contains_point(pos, Vec2::new(2.4, 4.4)));
         assert!(!cap1.contains_point(pos, Vec2::new(-6.6, 0.6)));
     }
+
+    #[test]
+    fn test_try_new() {
+        assert!(Capsule::try_new(Vec2::new(0.0, 1.0), 0.5).is_ok());
+        assert!(matches!(
+            Capsule::try_new(Vec2::new(0.0, 1.0), -0.5),
+            Err(ShapeError::NegativeSize)
+        ));
+        assert!(matches!(
+            Capsule::try_new(Vec2::new(f64::NAN, 1.0), 0.5),
+            Err(ShapeError::NonFinite)
+        ));
+        assert!(matches!(
+            Capsule::try_new(Vec2::zero(), 0.5),
+            Err(ShapeError::DegenerateGeometry)
+        ));
+    }
+
+    #[test]
+    fn test_circle_resolution() {
+        let cap = Capsule::new(Vec2::new(0.0, 1.0), 0.5);
+        let circ = Circle::new(0.5);
+
+        let res = cap.circle_resolution(Vec2::zero(), &circ, Vec2::new(0.8, 0.3), true);
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.2, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, -1.0, abs <= 0.0001);
+
+        // Past the end of the path the cap is round
+        let res = cap.circle_resolution(Vec2::zero(), &circ, Vec2::new(0.6, 1.8), true);
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.0, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, -0.6, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, -0.8, abs <= 0.0001);
+
+        let res = circ.collision_resolution(Vec2::new(0.8, 0.3), &cap, Vec2::zero());
+        assert_float_eq!(res.penetration, 0.2, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, 1.0, abs <= 0.0001);
+
+        assert!(!cap.collides(Vec2::zero(), &circ, Vec2::new(0.7, 1.8)));
+    }
+
+    #[test]
+    fn test_capsule_resolution() {
+        let cap = Capsule::new(Vec2::new(0.0, 1.0), 0.5);
+        let flat = Transform::new(Vec2::new(0.3, 0.0), FRAC_PI_2, 1.0);
+
+        // The paths cross, so the capsule leaves sideways past the nearer tip of the other
+        let res = cap.capsule_resolution(Vec2::zero(), &cap, flat, true);
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 1.7, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, -1.0, abs <= 0.0001);
+
+        let res = cap.collision_resolution(Vec2::zero(), &cap, Vec2::new(0.9, 0.5));
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, -1.0, abs <= 0.0001);
+
+        assert!(!cap.collides(Vec2::zero(), &cap, Vec2::new(0.0, 3.1)));
+        assert!(cap.collides(Vec2::zero(), &cap, Vec2::new(0.0, 2.9)));
+    }
+
+    #[test]
+    fn test_aabb_resolution() {
+        let cap = Capsule::new(Vec2::new(0.0, 1.0), 0.5);
+        let aabb = AABB::new(2.0, 2.0);
+
+        let res = cap.aabb_resolution(Vec2::new(0.0, 2.3), &aabb, Vec2::zero(), true);
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.2, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, 1.0, abs <= 0.0001);
+
+        let tilted = Transform::new(Vec2::new(1.9, 1.9), FRAC_PI_4, 1.0);
+        let res = cap.aabb_resolution(tilted, &aabb, Vec2::zero(), true);
+        assert!(!res.colliding);
+
+        let res = aabb.collision_resolution(Vec2::zero(), &cap, Vec2::new(1.3, 0.0));
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.2, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, -1.0, abs <= 0.0001);
+    }
 }
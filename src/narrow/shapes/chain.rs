@@ -0,0 +1,288 @@
+use crate::{
+    narrow::{
+        ray::{RayHit, Raycastable},
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+    },
+    transform::Transform,
+    utility::{closest_vertex, project_onto, Projection},
+    vec2::Vec2,
+    NumTolerance,
+};
+
+use super::{check_finite, segment::Segment, ShapeError, ShapeType, Shapeable};
+
+// A polyline of segments, each knowing its neighbours as ghost vertices so shapes slide across the joints.
+// A closed chain joins its last vertex back to the first
+pub struct Chain<T: NumTolerance> {
+    vertices: Vec<Vec2<T>>,
+    closed: bool,
+    segments: Vec<Segment<T>>,
+}
+
+impl<T: NumTolerance> Chain<T> {
+    pub fn new(vertices: Vec<Vec2<T>>, closed: bool, one_sided: bool) -> Self {
+        let count = vertices.len();
+        let edges = match closed {
+            true => count,
+            false => count.saturating_sub(1),
+        };
+        let ghost = |i: usize| match closed || i < count {
+            true => Some(vertices[i % count]),
+            false => None,
+        };
+
+        let segments = (0..edges)
+            .map(|i| {
+                let (start, end) = (vertices[i], vertices[(i + 1) % count]);
+                let segment = match one_sided {
+                    true => Segment::one_sided(start, end),
+                    false => Segment::new(start, end),
+                };
+                let previous = match i {
+                    0 if !closed => None,
+                    0 => Some(vertices[count - 1]),
+                    _ => Some(vertices[i - 1]),
+                };
+
+                segment.with_ghosts(previous, ghost(i + 2))
+            })
+            .collect();
+
+        Self {
+            vertices,
+            closed,
+            segments,
+        }
+    }
+
+    // Every segment needs a length for its normal, and a closed chain needs enough vertices to enclose something
+    pub fn try_new(
+        vertices: Vec<Vec2<T>>,
+        closed: bool,
+        one_sided: bool,
+    ) -> Result<Self, ShapeError> {
+        check_finite(&vertices)?;
+
+        let minimum = match closed {
+            true => 3,
+            false => 2,
+        };
+        if vertices.len() < minimum {
+            return Err(ShapeError::DegenerateGeometry);
+        }
+
+        let chain = Self::new(vertices, closed, one_sided);
+        if chain
+            .segments
+            .iter()
+            .any(|segment| (segment.end() - segment.start()).length_squared().is_zero())
+        {
+            return Err(ShapeError::DegenerateGeometry);
+        }
+
+        Ok(chain)
+    }
+
+    pub fn vertices(&self) -> &[Vec2<T>] {
+        &self.vertices
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn segments(&self) -> &[Segment<T>] {
+        &self.segments
+    }
+}
+
+impl<T: NumTolerance> SATable<T> for Chain<T> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        let transform = transform.into();
+
+        self.segments
+            .iter()
+            .flat_map(|segment| segment.axes(transform).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        project_onto(transform, &self.vertices, axis)
+    }
+
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        closest_vertex(point, transform, &self.vertices) - point
+    }
+
+    fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
+        let transform = transform.into();
+
+        self.segments
+            .iter()
+            .any(|segment| segment.contains_point(transform, point))
+    }
+
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
+    }
+
+    fn collision_resolution<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T>
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for Chain<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        let transform = transform.into();
+
+        self.segments
+            .iter()
+            .filter_map(|segment| segment.raycast(transform, origin, direction, max_toi))
+            .min_by(|hit, other| hit.toi.partial_cmp(&other.toi).unwrap())
+    }
+}
+
+impl<T: NumTolerance> Shapeable<T> for Chain<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
+        ShapeType::Chain(self)
+    }
+}
+
+#[cfg(test)]
+mod chain_tests {
+
+    use float_eq::assert_float_eq;
+
+    use super::Chain;
+    use crate::{
+        narrow::{
+            sat::SATable,
+            shapes::{aabb::AABB, ShapeError},
+        },
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_ghosts() {
+        let vertices = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+        ];
+
+        let open = Chain::new(vertices.clone(), false, true);
+        assert_eq!(open.segments().len(), 2);
+        assert!(open.segments()[0].previous().is_none());
+        assert_float_eq!(open.segments()[0].next().unwrap().y, 1.0, abs <= 0.0001);
+        assert_float_eq!(open.segments()[1].previous().unwrap().x, 0.0, abs <= 0.0001);
+        assert!(open.segments()[1].next().is_none());
+
+        let closed = Chain::new(vertices, true, false);
+        assert_eq!(closed.segments().len(), 3);
+        assert_float_eq!(
+            closed.segments()[0].previous().unwrap().y,
+            1.0,
+            abs <= 0.0001
+        );
+        assert_float_eq!(closed.segments()[2].next().unwrap().x, 1.0, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_no_snag() {
+        let ground = Chain::new(
+            vec![
+                Vec2::new(-4.0, 0.0),
+                Vec2::new(0.0, 0.0),
+                Vec2::new(4.0, 0.0),
+            ],
+            false,
+            true,
+        );
+        let aabb = AABB::new(1.0, 1.0);
+
+        // Sunk slightly into the ground just past the joint, the box would be pushed sideways by the first segment alone
+        for x in [-0.45, -0.2, 0.0, 0.2, 0.45] {
+            let res = aabb.collision_resolution(Vec2::new(x, 0.4), &ground, Vec2::zero());
+            assert!(res.colliding);
+            assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+            assert_float_eq!(res.axis.x, 0.0, abs <= 0.0001);
+            assert_float_eq!(res.axis.y, 1.0, abs <= 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_convex_corner() {
+        let ledge = Chain::new(
+            vec![
+                Vec2::new(-4.0, 0.0),
+                Vec2::new(0.0, 0.0),
+                Vec2::new(0.0, -4.0),
+            ],
+            false,
+            false,
+        );
+        let aabb = AABB::new(1.0, 1.0);
+
+        // Hanging over the edge of a ledge, the box can still be pushed off to the side
+        let res = aabb.collision_resolution(Vec2::new(0.45, -0.2), &ledge, Vec2::zero());
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.05, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, 1.0, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(
+            Chain::try_new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0)], false, true).is_ok()
+        );
+        assert!(Chain::try_new(
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(0.005, 0.0),
+                Vec2::new(0.005, 0.005)
+            ],
+            true,
+            false
+        )
+        .is_ok());
+        assert!(matches!(
+            Chain::try_new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0)], true, true),
+            Err(ShapeError::DegenerateGeometry)
+        ));
+        assert!(matches!(
+            Chain::try_new(
+                vec![
+                    Vec2::new(0.0, 0.0),
+                    Vec2::new(0.0, 0.0),
+                    Vec2::new(1.0, 0.0)
+                ],
+                false,
+                false
+            ),
+            Err(ShapeError::DegenerateGeometry)
+        ));
+    }
+}
@@ -1,14 +1,18 @@
 use crate::{
     narrow::{
-        sat::{Axis, Resolution, SATable},
-        shapes::{ShapeType, Shapeable},
+        gjk::Supportable,
+        ray::{raycast_circle, raycast_local, RayHit, Raycastable},
+        rounded::rounded_resolution,
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+        shapes::{check_size, ShapeError, ShapeType, Shapeable},
     },
+    transform::Transform,
     utility::Projection,
     vec2::Vec2,
     NumTolerance,
 };
 
-use super::aabb::AABB;
+use super::{aabb::AABB, p_gram::Pgram, polygon::Polygon, triangle::Triangle};
 
 pub struct Circle<T: NumTolerance> {
     radius: T,
@@ -19,6 +23,16 @@ impl<T: NumTolerance> Circle<T> {
         Self { radius }
     }
 
+    pub fn try_new(radius: T) -> Result<Self, ShapeError> {
+        check_size(radius)?;
+
+        Ok(Self::new(radius))
+    }
+
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
     pub fn aabb_resolution(
         &self,
         position: Vec2<T>,
@@ -92,65 +106,145 @@ impl<T: NumTolerance> Circle<T> {
 
         resolution
     }
+
+    pub fn polygon_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &Polygon<T>,
+        shape_transform: impl Into<Transform<T>>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        self.vertices_resolution(transform, shape.vertices(), shape_transform, resolve)
+    }
+
+    pub fn triangle_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &Triangle<T>,
+        shape_transform: impl Into<Transform<T>>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        self.vertices_resolution(transform, &shape.vertices(), shape_transform, resolve)
+    }
+
+    pub fn pgram_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &Pgram<T>,
+        shape_transform: impl Into<Transform<T>>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        self.vertices_resolution(transform, &shape.vertices(), shape_transform, resolve)
+    }
+
+    // Exact against any convex outline, whether the closest feature is a vertex or an edge
+    fn vertices_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        vertices: &[Vec2<T>],
+        shape_transform: impl Into<Transform<T>>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        let transform = transform.into();
+        let shape_transform = shape_transform.into();
+
+        let world: Vec<Vec2<T>> = vertices
+            .iter()
+            .map(|vertex| shape_transform.apply(*vertex))
+            .collect();
+
+        rounded_resolution(
+            &[transform.translation],
+            self.radius * transform.scale(),
+            &world,
+            T::zero(),
+            resolve,
+        )
+    }
 }
 
 impl<T: NumTolerance> SATable<T> for Circle<T> {
-    fn axes(&self) -> impl Iterator<Item = Axis<T>> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
         [Axis::Dynamic {
-            point: Vec2::zero(),
+            point: transform.into().translation,
         }]
         .into_iter()
     }
 
-    fn project(&self, axis: Vec2<T>, position: Vec2<T>) -> Projection<T> {
-        let dot = axis.dot(position);
-        let proj = self.radius * axis.length(); // The max and min will occur with parallel vectors, so the dot product is the product of the lengths
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        let transform = transform.into();
+        let dot = axis.dot(transform.translation);
+        let proj = self.radius * transform.scale() * axis.length(); // The max and min will occur with parallel vectors, so the dot product is the product of the lengths
         Projection {
             min: dot - proj,
             max: dot + proj,
         }
     }
 
-    fn axis_from_point(&self, position: Vec2<T>, point: Vec2<T>) -> Vec2<T> {
-        point - position
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        point - transform.into().translation
     }
 
-    fn collides<S>(&self, position: Vec2<T>, shape: &S, shape_position: Vec2<T>) -> bool
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
     where
         S: SATable<T> + Shapeable<T>,
     {
-        match shape.shape() {
-            ShapeType::AABB(aabb) => {
-                self.aabb_resolution(position, aabb, shape_position, false)
-                    .colliding
-            }
-            ShapeType::Circle(circ) => {
-                self.circle_resolution(position, circ, shape_position, false)
-                    .colliding
-            }
-            _ => self.sat_collides(position, shape, shape_position),
-        }
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
     }
 
     fn collision_resolution<S>(
         &self,
-        position: Vec2<T>,
+        transform: impl Into<Transform<T>>,
         shape: &S,
-        shape_position: Vec2<T>,
+        shape_transform: impl Into<Transform<T>>,
     ) -> Resolution<T>
     where
         S: SATable<T> + Shapeable<T>,
     {
-        match shape.shape() {
-            ShapeType::AABB(aabb) => self.aabb_resolution(position, aabb, shape_position, true),
-            ShapeType::Circle(circ) => self.circle_resolution(position, circ, shape_position, true),
-            _ => self.sat_collision_resolution(position, shape, shape_position),
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Supportable<T> for Circle<T> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T> {
+        let transform = transform.into();
+        let length = direction.length();
+
+        if length.is_trivial_abs() {
+            return transform.translation;
         }
+
+        transform.translation + direction.scale(self.radius * transform.scale() / length)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for Circle<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        raycast_local(
+            transform,
+            origin,
+            direction,
+            max_toi,
+            |origin, direction, max_toi| {
+                raycast_circle(Vec2::zero(), self.radius, origin, direction, max_toi)
+            },
+        )
     }
 }
 
 impl<T: NumTolerance> Shapeable<T> for Circle<T> {
-    fn shape(&self) -> ShapeType<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
         ShapeType::Circle(self)
     }
 }
@@ -160,9 +254,15 @@ mod circle_tests {
 
     use float_eq::assert_float_eq;
 
-    use super::Circle;
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::{Circle, ShapeError};
     use crate::{
-        narrow::sat::{Axis, SATable},
+        narrow::{
+            sat::{Axis, SATable},
+            shapes::{p_gram::Pgram, polygon::Polygon, triangle::Triangle},
+        },
+        transform::Transform,
         vec2::Vec2,
     };
 
@@ -174,10 +274,10 @@ mod circle_tests {
         let pos0 = Vec2::new(5.0, 2.0);
         let pos1 = Vec2::new(-1.0, -1.0);
 
-        for axis in circ0.axes() {
+        for axis in circ0.axes(pos0) {
             match axis {
                 Axis::Dynamic { point } => {
-                    let ax = circ0.axis_from_point(pos0 + point, pos1);
+                    let ax = circ0.axis_from_point(point, pos1);
                     assert_float_eq!(ax.x, -6.0, abs <= 0.01);
                     assert_float_eq!(ax.y, -3.0, abs <= 0.01);
                 }
@@ -188,10 +288,10 @@ mod circle_tests {
             }
         }
 
-        for axis in circ1.axes() {
+        for axis in circ1.axes(pos1) {
             match axis {
                 Axis::Dynamic { point } => {
-                    let ax = circ1.axis_from_point(pos1 + point, pos0);
+                    let ax = circ1.axis_from_point(point, pos0);
                     assert_float_eq!(ax.x, 6.0, abs <= 0.01);
                     assert_float_eq!(ax.y, 3.0, abs <= 0.01);
                 }
@@ -221,4 +321,76 @@ mod circle_tests {
         assert!(circ1.contains_point(pos0, Vec2::new(-2.5, 0.75)));
         assert!(!circ1.contains_point(pos1, Vec2::new(f32::sqrt(2.1), f32::sqrt(2.1))));
     }
+
+    #[test]
+    fn test_try_new() {
+        assert!(Circle::try_new(1.0).is_ok());
+        assert!(Circle::try_new(0.00001).is_ok());
+        assert!(matches!(
+            Circle::try_new(-1.0),
+            Err(ShapeError::NegativeSize)
+        ));
+        assert!(matches!(Circle::try_new(0.0), Err(ShapeError::ZeroSize)));
+        assert!(matches!(
+            Circle::try_new(f64::NAN),
+            Err(ShapeError::NonFinite)
+        ));
+        assert!(matches!(
+            Circle::try_new(f64::INFINITY),
+            Err(ShapeError::NonFinite)
+        ));
+    }
+
+    #[test]
+    fn test_polygonal_resolution() {
+        let circ = Circle::new(1.0);
+        let tri = Triangle::new(&[
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(0.0, 4.0),
+        ]);
+
+        // The hypotenuse is closer than any vertex
+        let res = circ.triangle_resolution(Vec2::new(2.5, 2.5), &tri, Vec2::zero(), true);
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 1.0 - f64::sqrt(0.5), abs <= 0.0001);
+        assert_float_eq!(res.axis.x, f64::sqrt(0.5), abs <= 0.0001);
+        assert_float_eq!(res.axis.y, f64::sqrt(0.5), abs <= 0.0001);
+
+        let res = tri.collision_resolution(Vec2::zero(), &circ, Vec2::new(2.0, -0.5));
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.5, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, 1.0, abs <= 0.0001);
+
+        // The center inside the outline leaves through the nearest edge
+        let res = circ.triangle_resolution(Vec2::new(0.5, 2.0), &tri, Vec2::zero(), true);
+        assert_float_eq!(res.penetration, 1.5, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, -1.0, abs <= 0.0001);
+
+        assert!(!circ.collides(Vec2::new(2.8, 2.8), &tri, Vec2::zero()));
+    }
+
+    #[test]
+    fn test_scaled_resolution() {
+        let circ = Circle::new(1.0);
+        let square = Pgram::new(Vec2::new(2.0, 0.0), Vec2::new(0.0, 2.0));
+        let poly = Polygon::new(vec![
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(1.0, -1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(-1.0, 1.0),
+        ])
+        .unwrap();
+        let doubled = Transform::new(Vec2::zero(), FRAC_PI_2, 2.0);
+
+        let res = circ.pgram_resolution(Vec2::new(0.0, 2.5), &square, doubled, true);
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.5, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, 1.0, abs <= 0.0001);
+
+        let res = poly.collision_resolution(doubled, &circ, Vec2::new(-2.5, 0.0));
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.5, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, 1.0, abs <= 0.0001);
+    }
 }
@@ -0,0 +1,203 @@
+use crate::{
+    broad::bounding_box::BoundingBox,
+    narrow::{
+        ray::{RayHit, Raycastable},
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+    },
+    transform::Transform,
+    utility::Projection,
+    vec2::Vec2,
+    NumTolerance,
+};
+
+use super::{shape::Shape, ShapeType, Shapeable};
+
+// Several shapes held at fixed offsets from a shared transform, colliding as one body
+pub struct Compound<T: NumTolerance> {
+    children: Vec<(Transform<T>, Shape<T>)>,
+}
+
+impl<T: NumTolerance> Compound<T> {
+    pub fn new(children: Vec<(Transform<T>, Shape<T>)>) -> Self {
+        Compound { children }
+    }
+
+    pub fn children(&self) -> &[(Transform<T>, Shape<T>)] {
+        &self.children
+    }
+
+    pub fn push(&mut self, offset: impl Into<Transform<T>>, child: impl Into<Shape<T>>) {
+        self.children.push((offset.into(), child.into()));
+    }
+}
+
+impl<T: NumTolerance> SATable<T> for Compound<T> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        let transform = transform.into();
+
+        self.children
+            .iter()
+            .flat_map(|(offset, child)| child.axes(transform.then(*offset)).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        let transform = transform.into();
+
+        self.children
+            .iter()
+            .map(|(offset, child)| child.project(axis, transform.then(*offset)))
+            .reduce(|proj, other| Projection {
+                min: match other.min < proj.min {
+                    true => other.min,
+                    false => proj.min,
+                },
+                max: match other.max > proj.max {
+                    true => other.max,
+                    false => proj.max,
+                },
+            })
+            .unwrap_or(Projection {
+                min: T::zero(),
+                max: T::zero(),
+            })
+    }
+
+    fn bounding_box(&self, transform: impl Into<Transform<T>>) -> BoundingBox<T> {
+        let transform = transform.into();
+
+        self.children
+            .iter()
+            .map(|(offset, child)| child.bounding_box(transform.then(*offset)))
+            .reduce(|bounds, other| bounds.merged(&other))
+            .unwrap_or(BoundingBox::new(
+                transform.translation,
+                transform.translation,
+            ))
+    }
+
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        let transform = transform.into();
+
+        self.children
+            .iter()
+            .map(|(offset, child)| child.axis_from_point(transform.then(*offset), point))
+            .min_by(|axis, other| {
+                axis.length_squared()
+                    .partial_cmp(&other.length_squared())
+                    .unwrap()
+            })
+            .unwrap_or(Vec2::zero())
+    }
+
+    fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
+        let transform = transform.into();
+
+        self.children
+            .iter()
+            .any(|(offset, child)| child.contains_point(transform.then(*offset), point))
+    }
+
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
+    }
+
+    fn collision_resolution<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T>
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for Compound<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        let transform = transform.into();
+
+        self.children
+            .iter()
+            .filter_map(|(offset, child)| {
+                child.raycast(transform.then(*offset), origin, direction, max_toi)
+            })
+            .min_by(|hit, other| hit.toi.partial_cmp(&other.toi).unwrap())
+    }
+}
+
+impl<T: NumTolerance> Shapeable<T> for Compound<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
+        ShapeType::Compound(self)
+    }
+}
+
+#[cfg(test)]
+mod compound_tests {
+
+    use float_eq::assert_float_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::Compound;
+    use crate::{
+        narrow::{
+            sat::SATable,
+            shapes::{aabb::AABB, circle::Circle},
+        },
+        transform::Transform,
+        vec2::Vec2,
+    };
+
+    // A dumbbell: two balls joined by a thin bar along the x axis
+    fn dumbbell() -> Compound<f64> {
+        let mut compound = Compound::new(Vec::new());
+        compound.push(Vec2::new(-2.0, 0.0), Circle::new(1.0));
+        compound.push(Vec2::new(2.0, 0.0), Circle::new(1.0));
+        compound.push(Vec2::zero(), AABB::new(4.0, 0.2));
+
+        compound
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let bell = dumbbell();
+
+        assert!(bell.contains_point(Vec2::zero(), Vec2::new(-2.5, 0.5)));
+        assert!(bell.contains_point(Vec2::zero(), Vec2::new(0.0, 0.05)));
+        assert!(!bell.contains_point(Vec2::zero(), Vec2::new(0.0, 0.5)));
+
+        let upright = Transform::new(Vec2::new(1.0, 0.0), FRAC_PI_2, 1.0);
+        assert!(bell.contains_point(upright, Vec2::new(1.0, 2.5)));
+        assert!(!bell.contains_point(upright, Vec2::new(3.0, 0.0)));
+    }
+
+    #[test]
+    fn test_project() {
+        let bell = dumbbell();
+
+        let proj = bell.project(Vec2::new(1.0, 0.0), Vec2::new(1.0, 0.0));
+        assert_float_eq!(proj.min, -2.0, abs <= 0.0001);
+        assert_float_eq!(proj.max, 4.0, abs <= 0.0001);
+
+        let proj = bell.project(Vec2::new(0.0, 1.0), Transform::new(Vec2::zero(), 0.0, 2.0));
+        assert_float_eq!(proj.min, -2.0, abs <= 0.0001);
+        assert_float_eq!(proj.max, 2.0, abs <= 0.0001);
+    }
+}
@@ -0,0 +1,369 @@
+use crate::{
+    narrow::{
+        gjk::Supportable,
+        ray::{RayHit, Raycastable},
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+    },
+    transform::Transform,
+    utility::{closest_vertex, project_onto, support_vertex, Projection},
+    vec2::Vec2,
+    NumTolerance,
+};
+
+use super::{
+    check_finite,
+    polygon::{is_straight, self_intersecting, signed_area, Polygon, PolygonError},
+    ShapeType, Shapeable,
+};
+
+// A simple polygon split into convex pieces, which collide on behalf of the whole
+pub struct ConcavePolygon<T: NumTolerance> {
+    vertices: Vec<Vec2<T>>,
+    pieces: Vec<Polygon<T>>,
+}
+
+impl<T: NumTolerance> ConcavePolygon<T> {
+    pub fn new(vertices: Vec<Vec2<T>>) -> Result<ConcavePolygon<T>, PolygonError> {
+        check_finite(&vertices).map_err(|_| PolygonError::NonFinite)?;
+
+        let mut vertices = without_collinear(vertices);
+        if vertices.len() <= 2 {
+            return Err(PolygonError::TooFewVerticesError);
+        }
+
+        if self_intersecting(&vertices) {
+            return Err(PolygonError::SelfIntersecting);
+        }
+
+        if signed_area(&vertices).is_sign_negative() {
+            vertices.reverse();
+        }
+
+        let pieces = merge_pieces(&vertices, triangulate(&vertices)?)
+            .into_iter()
+            .map(|piece| Polygon::new(piece.into_iter().map(|i| vertices[i]).collect()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ConcavePolygon { vertices, pieces })
+    }
+
+    pub fn vertices(&self) -> &[Vec2<T>] {
+        &self.vertices
+    }
+
+    pub fn pieces(&self) -> &[Polygon<T>] {
+        &self.pieces
+    }
+}
+
+fn turn<T: NumTolerance>(prev: Vec2<T>, vertex: Vec2<T>, next: Vec2<T>) -> T {
+    (vertex - prev).cross(next - vertex)
+}
+
+fn without_collinear<T: NumTolerance>(mut vertices: Vec<Vec2<T>>) -> Vec<Vec2<T>> {
+    let mut i = 0;
+    while vertices.len() > 2 && i < vertices.len() {
+        let len = vertices.len();
+        let (prev, next) = (vertices[(i + len - 1) % len], vertices[(i + 1) % len]);
+
+        match is_straight(vertices[i] - prev, next - vertices[i]) {
+            true => {
+                vertices.remove(i);
+                i = i.saturating_sub(1);
+            }
+            false => i += 1,
+        }
+    }
+
+    vertices
+}
+
+fn in_triangle<T: NumTolerance>(point: Vec2<T>, a: Vec2<T>, b: Vec2<T>, c: Vec2<T>) -> bool {
+    (b - a).cross(point - a) >= T::zero()
+        && (c - b).cross(point - b) >= T::zero()
+        && (a - c).cross(point - c) >= T::zero()
+}
+
+// Ear clipping over a counter-clockwise outline, returning triangles of vertex indices
+fn triangulate<T: NumTolerance>(vertices: &[Vec2<T>]) -> Result<Vec<Vec<usize>>, PolygonError> {
+    let mut remaining: Vec<usize> = (0..vertices.len()).collect();
+    let mut triangles = Vec::new();
+
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        let ear = (0..len).find(|&i| {
+            let (prev, vertex, next) = (
+                remaining[(i + len - 1) % len],
+                remaining[i],
+                remaining[(i + 1) % len],
+            );
+
+            turn(vertices[prev], vertices[vertex], vertices[next]) > T::zero()
+                && remaining
+                    .iter()
+                    .filter(|&&other| other != prev && other != vertex && other != next)
+                    .all(|&other| {
+                        !in_triangle(
+                            vertices[other],
+                            vertices[prev],
+                            vertices[vertex],
+                            vertices[next],
+                        )
+                    })
+        });
+
+        let i = ear.ok_or(PolygonError::DecompositionError)?;
+        triangles.push(vec![
+            remaining[(i + len - 1) % len],
+            remaining[i],
+            remaining[(i + 1) % len],
+        ]);
+        remaining.remove(i);
+    }
+
+    triangles.push(remaining);
+
+    Ok(triangles)
+}
+
+// Hertel-Mehlhorn: drop every diagonal whose removal keeps both sides convex
+fn merge_pieces<T: NumTolerance>(
+    vertices: &[Vec2<T>],
+    mut pieces: Vec<Vec<usize>>,
+) -> Vec<Vec<usize>> {
+    let mut merged = true;
+
+    while merged {
+        merged = false;
+
+        'search: for i in 0..pieces.len() {
+            for j in (i + 1)..pieces.len() {
+                if let Some(piece) = merge(vertices, &pieces[i], &pieces[j]) {
+                    pieces[i] = piece;
+                    pieces.remove(j);
+                    merged = true;
+                    break 'search;
+                }
+            }
+        }
+    }
+
+    pieces
+}
+
+fn merge<T: NumTolerance>(
+    vertices: &[Vec2<T>],
+    piece: &[usize],
+    other: &[usize],
+) -> Option<Vec<usize>> {
+    let (len, other_len) = (piece.len(), other.len());
+
+    let (k, m) = (0..len).find_map(|k| {
+        let (a, b) = (piece[k], piece[(k + 1) % len]);
+        (0..other_len)
+            .find(|&m| other[m] == b && other[(m + 1) % other_len] == a)
+            .map(|m| (k, m))
+    })?;
+
+    let merged: Vec<usize> = (0..len)
+        .map(|n| piece[(k + 1 + n) % len])
+        .chain((0..other_len - 2).map(|n| other[(m + 2 + n) % other_len]))
+        .collect();
+
+    let convex = (0..merged.len()).all(|n| {
+        let prev = vertices[merged[(n + merged.len() - 1) % merged.len()]];
+        let (vertex, next) = (
+            vertices[merged[n]],
+            vertices[merged[(n + 1) % merged.len()]],
+        );
+
+        turn(prev, vertex, next) > T::zero() && !is_straight(vertex - prev, next - vertex)
+    });
+
+    match convex {
+        true => Some(merged),
+        false => None,
+    }
+}
+
+impl<T: NumTolerance> SATable<T> for ConcavePolygon<T> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        let transform = transform.into();
+
+        self.pieces
+            .iter()
+            .flat_map(|piece| piece.axes(transform).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        project_onto(transform, self.vertices(), axis)
+    }
+
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        closest_vertex(point, transform, self.vertices()) - point
+    }
+
+    fn convex_pieces(&self) -> Option<&[Polygon<T>]> {
+        Some(&self.pieces)
+    }
+
+    fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
+        let transform = transform.into();
+
+        self.pieces
+            .iter()
+            .any(|piece| piece.contains_point(transform, point))
+    }
+
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
+    }
+
+    fn collision_resolution<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T>
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+// The support of the whole outline is that of its hull, so the support-based queries go through the pieces instead
+impl<T: NumTolerance> Supportable<T> for ConcavePolygon<T> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T> {
+        support_vertex(transform, self.vertices(), direction)
+    }
+
+    fn support_pieces(&self) -> Option<&[Polygon<T>]> {
+        Some(&self.pieces)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for ConcavePolygon<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        let transform = transform.into();
+
+        self.pieces
+            .iter()
+            .filter_map(|piece| piece.raycast(transform, origin, direction, max_toi))
+            .min_by(|hit, other| hit.toi.partial_cmp(&other.toi).unwrap())
+    }
+}
+
+impl<T: NumTolerance> Shapeable<T> for ConcavePolygon<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
+        ShapeType::Concave(self)
+    }
+}
+
+#[cfg(test)]
+mod concave_tests {
+
+    use super::ConcavePolygon;
+    use crate::{
+        narrow::{sat::SATable, shapes::polygon::PolygonError},
+        vec2::Vec2,
+    };
+
+    fn l_shape() -> ConcavePolygon<f64> {
+        ConcavePolygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_decomposition() {
+        assert_eq!(l_shape().pieces().len(), 2);
+
+        let star = ConcavePolygon::new(vec![
+            Vec2::new(0.0, 3.0),
+            Vec2::new(-1.0, 1.0),
+            Vec2::new(-3.0, 0.0),
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(0.0, -3.0),
+            Vec2::new(1.0, -1.0),
+            Vec2::new(3.0, 0.0),
+            Vec2::new(1.0, 1.0),
+        ])
+        .unwrap();
+        assert!(star.pieces().len() <= 5);
+
+        // Clockwise outlines and collinear vertices are accepted
+        let clockwise = ConcavePolygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 2.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(1.0, 0.0),
+        ])
+        .unwrap();
+        assert_eq!(clockwise.vertices().len(), 6);
+        assert_eq!(clockwise.pieces().len(), 2);
+
+        assert!(ConcavePolygon::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0)]).is_err());
+
+        let small = ConcavePolygon::new(
+            l_shape()
+                .vertices()
+                .iter()
+                .map(|vertex| vertex.scale(0.005))
+                .collect(),
+        )
+        .unwrap();
+        assert_eq!(small.pieces().len(), 2);
+
+        let bowtie = ConcavePolygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0),
+        ]);
+        assert!(matches!(bowtie, Err(PolygonError::SelfIntersecting)));
+
+        let nan = ConcavePolygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, f64::NAN),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ]);
+        assert!(matches!(nan, Err(PolygonError::NonFinite)));
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let wall = l_shape();
+
+        assert!(wall.contains_point(Vec2::zero(), Vec2::new(1.5, 0.5)));
+        assert!(wall.contains_point(Vec2::zero(), Vec2::new(0.5, 1.5)));
+        assert!(!wall.contains_point(Vec2::zero(), Vec2::new(1.5, 1.5)));
+        assert!(!wall.contains_point(Vec2::new(1.0, 1.0), Vec2::new(2.5, 2.5)));
+    }
+}
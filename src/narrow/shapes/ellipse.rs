@@ -0,0 +1,481 @@
+use std::f64::consts::PI;
+
+use crate::{
+    narrow::{
+        epa::epa_collision_resolution,
+        gjk::{gjk_collides, Supportable},
+        ray::{inside_normal, raycast_circle, raycast_local, RayHit, Raycastable},
+        rounded::RoundedFeature,
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+        shapes::{check_size, ShapeError, ShapeType, Shapeable},
+    },
+    transform::Transform,
+    utility::Projection,
+    vec2::Vec2,
+    NumTolerance,
+};
+
+static AXIS_SEARCH_FAN: usize = 16;
+static AXIS_SEARCH_ITERATIONS: usize = 32;
+
+// An ellipse centred on its transform, with `radii` along its local x and y axes.
+// Its outline is curved everywhere, so it collides through its support mapping rather than through a set of axes
+pub struct Ellipse<T: NumTolerance> {
+    radii: Vec2<T>,
+}
+
+impl<T: NumTolerance> Ellipse<T> {
+    pub fn new(x_radius: T, y_radius: T) -> Self {
+        Self {
+            radii: Vec2::new(x_radius, y_radius),
+        }
+    }
+
+    pub fn try_new(x_radius: T, y_radius: T) -> Result<Self, ShapeError> {
+        check_size(x_radius)?;
+        check_size(y_radius)?;
+
+        Ok(Self::new(x_radius, y_radius))
+    }
+
+    pub fn radii(&self) -> Vec2<T> {
+        self.radii
+    }
+
+    pub fn ellipse_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &Ellipse<T>,
+        shape_transform: impl Into<Transform<T>>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        support_resolution(
+            self,
+            transform.into(),
+            shape,
+            shape_transform.into(),
+            resolve,
+        )
+    }
+
+    pub(crate) fn feature_resolution(
+        &self,
+        transform: Transform<T>,
+        feature: &RoundedFeature<T>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        support_resolution(
+            self,
+            transform,
+            feature,
+            Transform::from(Vec2::zero()),
+            resolve,
+        )
+    }
+
+    // Shapes without a support mapping can still be projected, so the axis of least overlap is searched for directly:
+    // a fan of directions and the other shape's own axes are tried, then the best is refined by golden section search
+    pub(crate) fn projection_resolution(
+        &self,
+        transform: Transform<T>,
+        shape: &(impl SATable<T> + ?Sized),
+        shape_transform: Transform<T>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        let overlap = |angle: T| {
+            let axis = Vec2::new(angle.cos(), angle.sin());
+            let (proj, other) = (
+                self.project(axis, transform),
+                shape.project(axis, shape_transform),
+            );
+
+            match other.max - proj.min < proj.max - other.min {
+                true => (other.max - proj.min, axis),
+                false => (proj.max - other.min, -axis),
+            }
+        };
+        let separated = |overlap: T| overlap.is_sign_negative() && !overlap.is_trivial_abs();
+
+        let pi = T::from(PI).unwrap();
+        let step = pi / T::from(AXIS_SEARCH_FAN).unwrap();
+
+        let fan = (0..AXIS_SEARCH_FAN).map(|i| T::from(i).unwrap() * step);
+        let own_axes = shape
+            .axes(shape_transform)
+            .map(|axis| match axis {
+                Axis::Static { vector, .. } => vector,
+                Axis::Dynamic { point } => transform.translation - point,
+            })
+            .filter(|vector| !vector.length_squared().is_trivial_abs())
+            .map(|vector| vector.y.atan2(vector.x))
+            .collect::<Vec<_>>();
+
+        let mut best = (T::zero(), T::max_value());
+        for angle in fan.chain(own_axes) {
+            let (amount, _) = overlap(angle);
+            if separated(amount) {
+                return Resolution::new();
+            }
+
+            if amount < best.1 {
+                best = (angle, amount);
+            }
+        }
+
+        let ratio = T::from(0.5 * (5.0_f64.sqrt() - 1.0)).unwrap();
+        let (mut low, mut high) = (best.0 - step, best.0 + step);
+        for _ in 0..AXIS_SEARCH_ITERATIONS {
+            let (left, right) = (high - (high - low) * ratio, low + (high - low) * ratio);
+            let (left_amount, right_amount) = (overlap(left).0, overlap(right).0);
+
+            for (angle, amount) in [(left, left_amount), (right, right_amount)] {
+                if separated(amount) {
+                    return Resolution::new();
+                }
+
+                if amount < best.1 {
+                    best = (angle, amount);
+                }
+            }
+
+            match left_amount < right_amount {
+                true => high = right,
+                false => low = left,
+            }
+        }
+
+        let (penetration, axis) = overlap(best.0);
+        match resolve {
+            true => Resolution {
+                colliding: true,
+                penetration,
+                axis,
+            },
+            false => Resolution {
+                colliding: true,
+                ..Resolution::new()
+            },
+        }
+    }
+
+    // Half the extent of the ellipse along a local axis, scaled by the axis length like `Capsule::project`
+    fn extent(&self, local_axis: Vec2<T>) -> T {
+        let (x, y) = (self.radii.x * local_axis.x, self.radii.y * local_axis.y);
+
+        (x * x + y * y).sqrt()
+    }
+}
+
+fn support_resolution<T: NumTolerance>(
+    ellipse: &Ellipse<T>,
+    transform: Transform<T>,
+    shape: &impl Supportable<T>,
+    shape_transform: Transform<T>,
+    resolve: bool,
+) -> Resolution<T> {
+    match resolve {
+        true => epa_collision_resolution(ellipse, transform, shape, shape_transform),
+        false => Resolution {
+            colliding: gjk_collides(ellipse, transform, shape, shape_transform),
+            ..Resolution::new()
+        },
+    }
+}
+
+impl<T: NumTolerance> SATable<T> for Ellipse<T> {
+    // Only the principal axes are finite, which is enough for `contains_point` but not for SAT against other shapes
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        let transform = transform.into();
+
+        [
+            Axis::Static {
+                vector: transform.rotate(Vec2::new(T::one(), T::zero())),
+                normalized: true,
+            },
+            Axis::Static {
+                vector: transform.rotate(Vec2::new(T::zero(), T::one())),
+                normalized: true,
+            },
+        ]
+        .into_iter()
+    }
+
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        let transform = transform.into();
+        let dot = axis.dot(transform.translation);
+        let extent = self.extent(transform.inverse_rotate(axis)) * transform.scale();
+
+        Projection {
+            min: dot - extent,
+            max: dot + extent,
+        }
+    }
+
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        transform.into().translation - point
+    }
+
+    fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
+        let local = transform.into().inverse_apply(point);
+        let (x, y) = (local.x / self.radii.x, local.y / self.radii.y);
+
+        (x * x + y * y).is_difference_small(T::one())
+    }
+
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
+    }
+
+    fn collision_resolution<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T>
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Supportable<T> for Ellipse<T> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T> {
+        let transform = transform.into();
+        let local = transform.inverse_rotate(direction);
+
+        // The point whose outward normal is `local`, found by stretching the direction by the squared radii
+        let extent = self.extent(local);
+        if extent.is_zero() {
+            return transform.translation;
+        }
+
+        transform.apply(Vec2::new(
+            self.radii.x * self.radii.x * local.x / extent,
+            self.radii.y * self.radii.y * local.y / extent,
+        ))
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for Ellipse<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        raycast_local(
+            transform,
+            origin,
+            direction,
+            max_toi,
+            |origin, direction, max_toi| {
+                // Squashing the ellipse into a unit circle keeps the time of impact unchanged
+                let squash =
+                    |vector: Vec2<T>| Vec2::new(vector.x / self.radii.x, vector.y / self.radii.y);
+
+                let (toi, point) = raycast_circle(
+                    Vec2::zero(),
+                    T::one(),
+                    squash(origin),
+                    squash(direction),
+                    max_toi,
+                )?;
+
+                match toi.is_zero() {
+                    true => Some((toi, inside_normal(direction))),
+                    false => Some((toi, squash(point).normalized())),
+                }
+            },
+        )
+    }
+}
+
+impl<T: NumTolerance> Shapeable<T> for Ellipse<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
+        ShapeType::Ellipse(self)
+    }
+}
+
+#[cfg(test)]
+mod ellipse_tests {
+
+    use float_eq::assert_float_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::{Ellipse, ShapeError};
+    use crate::{
+        narrow::{
+            gjk::Supportable,
+            ray::Raycastable,
+            sat::SATable,
+            shapes::{aabb::AABB, polygon::Polygon},
+        },
+        transform::Transform,
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_project() {
+        let ell = Ellipse::new(2.0, 1.0);
+
+        let proj = ell.project(Vec2::new(1.0, 0.0), Vec2::new(1.0, 0.0));
+        assert_float_eq!(proj.min, -1.0, abs <= 0.0001);
+        assert_float_eq!(proj.max, 3.0, abs <= 0.0001);
+
+        let proj = ell.project(Vec2::new(1.0, 1.0).normalized(), Vec2::zero());
+        assert_float_eq!(proj.max, f64::sqrt(2.5), abs <= 0.0001);
+
+        let upright = Transform::new(Vec2::zero(), FRAC_PI_2, 2.0);
+        let proj = ell.project(Vec2::new(0.0, 1.0), upright);
+        assert_float_eq!(proj.max, 4.0, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_support() {
+        let ell = Ellipse::new(2.0, 1.0);
+
+        let point = ell.support(Vec2::zero(), Vec2::new(1.0, 1.0));
+        assert_float_eq!(point.x, 4.0 / f64::sqrt(5.0), abs <= 0.0001);
+        assert_float_eq!(point.y, 1.0 / f64::sqrt(5.0), abs <= 0.0001);
+
+        let point = ell.support(
+            Transform::new(Vec2::new(1.0, 0.0), FRAC_PI_2, 1.0),
+            Vec2::new(0.0, 1.0),
+        );
+        assert_float_eq!(point.x, 1.0, abs <= 0.0001);
+        assert_float_eq!(point.y, 2.0, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let ell = Ellipse::new(2.0, 1.0);
+
+        assert!(ell.contains_point(Vec2::zero(), Vec2::new(1.9, 0.0)));
+        assert!(!ell.contains_point(Vec2::zero(), Vec2::new(1.5, 0.8)));
+        assert!(ell.contains_point(
+            Transform::new(Vec2::zero(), FRAC_PI_2, 1.0),
+            Vec2::new(0.0, 1.9)
+        ));
+    }
+
+    #[test]
+    fn test_collision() {
+        let ell = Ellipse::new(2.0, 1.0);
+        let aabb = AABB::new(2.0, 2.0);
+        let tri = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0),
+        ])
+        .unwrap();
+
+        let res = ell.collision_resolution(Vec2::zero(), &aabb, Vec2::new(0.0, 1.9));
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, -1.0, abs <= 0.0001);
+
+        let res = aabb.collision_resolution(Vec2::new(2.9, 0.0), &ell, Vec2::zero());
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, 1.0, abs <= 0.0001);
+
+        assert!(ell.collides(
+            Transform::new(Vec2::zero(), FRAC_PI_2, 1.0),
+            &tri,
+            Vec2::new(-0.5, 1.5)
+        ));
+        assert!(!ell.collides(Vec2::zero(), &tri, Vec2::new(-0.5, 1.5)));
+        assert!(!ell.collides(Vec2::zero(), &ell, Vec2::new(4.1, 0.0)));
+        assert!(ell.collides(Vec2::zero(), &ell, Vec2::new(3.9, 0.0)));
+    }
+
+    #[test]
+    fn test_projection_resolution() {
+        let ell = Ellipse::new(2.0, 1.0);
+        let aabb = AABB::new(2.0, 2.0);
+
+        let res =
+            ell.projection_resolution(Vec2::zero().into(), &aabb, Vec2::new(0.0, 1.9).into(), true);
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, -1.0, abs <= 0.0001);
+
+        // Against a corner the searched axis agrees with the support mapping
+        let corner = Transform::from(Vec2::new(2.2, 1.2));
+        let expected = ell.collision_resolution(Vec2::zero(), &aabb, corner);
+        let res = ell.projection_resolution(Vec2::zero().into(), &aabb, corner, true);
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, expected.penetration, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, expected.axis.x, abs <= 0.001);
+        assert_float_eq!(res.axis.y, expected.axis.y, abs <= 0.001);
+
+        assert!(
+            !ell.projection_resolution(
+                Vec2::zero().into(),
+                &aabb,
+                Vec2::new(3.0, 1.8).into(),
+                true
+            )
+            .colliding
+        );
+    }
+
+    #[test]
+    fn test_raycast() {
+        let ell = Ellipse::new(2.0, 1.0);
+
+        let hit = ell
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(-5.0, 0.0),
+                Vec2::new(1.0, 0.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 3.0, abs <= 0.0001);
+        assert_float_eq!(hit.normal.x, -1.0, abs <= 0.0001);
+
+        let hit = ell
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(0.0, 5.0),
+                Vec2::new(0.0, -1.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 4.0, abs <= 0.0001);
+        assert_float_eq!(hit.normal.y, 1.0, abs <= 0.0001);
+
+        assert!(ell
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(-5.0, 1.1),
+                Vec2::new(1.0, 0.0),
+                10.0
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(Ellipse::try_new(2.0, 1.0).is_ok());
+        assert!(matches!(
+            Ellipse::try_new(2.0, 0.0),
+            Err(ShapeError::ZeroSize)
+        ));
+        assert!(matches!(
+            Ellipse::try_new(-2.0, 1.0),
+            Err(ShapeError::NegativeSize)
+        ));
+    }
+}
@@ -0,0 +1,397 @@
+use crate::{
+    broad::bounding_box::BoundingBox,
+    narrow::{
+        ray::{inside_normal, RayHit, Raycastable},
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+    },
+    transform::Transform,
+    utility::Projection,
+    vec2::Vec2,
+    NumTolerance,
+};
+
+use super::{check_finite, ShapeError, ShapeType, Shapeable};
+
+// Everything behind a line, the points `p` with `p.dot(normal) <= offset`
+pub struct HalfPlane<T: NumTolerance> {
+    normal: Vec2<T>,
+    offset: T,
+}
+
+impl<T: NumTolerance> HalfPlane<T> {
+    // The normal is normalized, with the offset scaled to keep the same line
+    pub fn new(normal: Vec2<T>, offset: T) -> Self {
+        let length = normal.length();
+        debug_assert!(
+            length.is_finite() && length > T::zero(),
+            "half-plane normal must be nonzero and finite"
+        );
+
+        Self {
+            normal: normal.scale(T::one() / length),
+            offset: offset / length,
+        }
+    }
+
+    pub fn try_new(normal: Vec2<T>, offset: T) -> Result<Self, ShapeError> {
+        check_finite(&[normal, Vec2::new(offset, T::zero())])?;
+
+        if normal.length_squared().is_trivial_abs() {
+            return Err(ShapeError::DegenerateGeometry);
+        }
+
+        Ok(Self::new(normal, offset))
+    }
+
+    pub fn normal(&self) -> Vec2<T> {
+        self.normal
+    }
+
+    pub fn offset(&self) -> T {
+        self.offset
+    }
+
+    // The other shape's deepest point comes straight from its projection
+    pub fn shape_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &(impl SATable<T> + ?Sized),
+        shape_transform: impl Into<Transform<T>>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        let (normal, offset) = self.world(transform.into());
+        let depth = offset - shape.project(normal, shape_transform).min;
+
+        let mut resolution = Resolution::new();
+        if T::zero().is_difference_small(depth) {
+            resolution.colliding = true;
+
+            if resolve {
+                resolution.penetration = depth;
+                resolution.axis = -normal;
+            }
+        }
+
+        resolution
+    }
+
+    // Only facing planes can be pushed apart, any other pair overlaps without a penetration
+    pub fn plane_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        other: &HalfPlane<T>,
+        other_transform: impl Into<Transform<T>>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        let (normal, offset) = self.world(transform.into());
+        let (other_normal, other_offset) = other.world(other_transform.into());
+
+        let facing =
+            normal.cross(other_normal).is_trivial_abs() && normal.dot(other_normal) < T::zero();
+        let depth = match facing {
+            true => offset + other_offset,
+            false => T::zero(),
+        };
+
+        let mut resolution = Resolution::new();
+        if T::zero().is_difference_small(depth) {
+            resolution.colliding = true;
+
+            if resolve {
+                resolution.penetration = depth;
+                resolution.axis = -normal;
+            }
+        }
+
+        resolution
+    }
+
+    fn world(&self, transform: Transform<T>) -> (Vec2<T>, T) {
+        let normal = transform.rotate(self.normal);
+
+        (
+            normal,
+            self.offset * transform.scale() + normal.dot(transform.translation),
+        )
+    }
+}
+
+impl<T: NumTolerance> SATable<T> for HalfPlane<T> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        [Axis::Static {
+            vector: transform.into().rotate(self.normal),
+            normalized: true,
+        }]
+        .into_iter()
+    }
+
+    // Bounded only along the normal, and then on one side
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        let (normal, offset) = self.world(transform.into());
+        let (min, max) = (T::min_value(), T::max_value());
+
+        if !normal.cross(axis).is_trivial_abs() {
+            return Projection { min, max };
+        }
+
+        let edge = offset * normal.dot(axis);
+        match normal.dot(axis).is_sign_negative() {
+            true => Projection { min: edge, max },
+            false => Projection { min, max: edge },
+        }
+    }
+
+    // Infinite on the open sides, so keep half-planes out of the broad phase
+    fn bounding_box(&self, transform: impl Into<Transform<T>>) -> BoundingBox<T> {
+        let transform = transform.into();
+        let unbounded = |proj: Projection<T>| {
+            (
+                match proj.min == T::min_value() {
+                    true => T::neg_infinity(),
+                    false => proj.min,
+                },
+                match proj.max == T::max_value() {
+                    true => T::infinity(),
+                    false => proj.max,
+                },
+            )
+        };
+        let (min_x, max_x) = unbounded(self.project(Vec2::new(T::one(), T::zero()), transform));
+        let (min_y, max_y) = unbounded(self.project(Vec2::new(T::zero(), T::one()), transform));
+
+        BoundingBox::new(Vec2::new(min_x, min_y), Vec2::new(max_x, max_y))
+    }
+
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, _point: Vec2<T>) -> Vec2<T> {
+        -transform.into().rotate(self.normal)
+    }
+
+    fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
+        let transform = transform.into();
+
+        // Compared as a depth, so the tolerance doesn't grow with the offset
+        let height = transform
+            .rotate(self.normal)
+            .dot(point - transform.translation);
+        T::zero().is_difference_small(self.offset * transform.scale() - height)
+    }
+
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
+    }
+
+    fn collision_resolution<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T>
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for HalfPlane<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        let (normal, offset) = self.world(transform.into());
+        let height = origin.dot(normal) - offset;
+
+        if !height.is_sign_positive() {
+            return Some(RayHit {
+                toi: T::zero(),
+                point: origin,
+                normal: inside_normal(direction),
+            });
+        }
+
+        let speed = direction.dot(normal);
+        if !speed.is_sign_negative() || speed.is_zero() {
+            return None;
+        }
+
+        let toi = -height / speed;
+        match toi > max_toi {
+            true => None,
+            false => Some(RayHit {
+                toi,
+                point: origin + direction.scale(toi),
+                normal,
+            }),
+        }
+    }
+}
+
+impl<T: NumTolerance> Shapeable<T> for HalfPlane<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
+        ShapeType::HalfPlane(self)
+    }
+}
+
+#[cfg(test)]
+mod half_plane_tests {
+
+    use float_eq::assert_float_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    use super::{HalfPlane, ShapeError};
+    use crate::{
+        narrow::{
+            ray::Raycastable,
+            sat::SATable,
+            shapes::{aabb::AABB, circle::Circle},
+        },
+        transform::Transform,
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_project() {
+        let floor = HalfPlane::new(Vec2::new(0.0, 2.0), 2.0);
+        assert_float_eq!(floor.normal().y, 1.0, abs <= 0.0001);
+        assert_float_eq!(floor.offset(), 1.0, abs <= 0.0001);
+
+        let proj = floor.project(Vec2::new(0.0, 1.0), Vec2::new(0.0, 1.0));
+        assert_eq!(proj.min, f64::MIN);
+        assert_float_eq!(proj.max, 2.0, abs <= 0.0001);
+
+        let proj = floor.project(Vec2::new(0.0, -2.0), Vec2::zero());
+        assert_float_eq!(proj.min, -2.0, abs <= 0.0001);
+        assert_eq!(proj.max, f64::MAX);
+
+        let proj = floor.project(Vec2::new(1.0, 1.0), Vec2::zero());
+        assert_eq!(proj.min, f64::MIN);
+        assert_eq!(proj.max, f64::MAX);
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let floor = HalfPlane::new(Vec2::new(0.0, 1.0), 0.0);
+        let wall = Transform::new(Vec2::new(3.0, 0.0), FRAC_PI_2, 1.0);
+
+        assert!(floor.contains_point(Vec2::zero(), Vec2::new(100.0, -0.5)));
+        assert!(!floor.contains_point(Vec2::zero(), Vec2::new(0.0, 0.5)));
+        assert!(floor.contains_point(wall, Vec2::new(3.5, 50.0)));
+        assert!(!floor.contains_point(wall, Vec2::new(2.5, 0.0)));
+    }
+
+    #[test]
+    fn test_resolution() {
+        let floor = HalfPlane::new(Vec2::new(0.0, 1.0), 0.0);
+        let circ = Circle::new(1.0);
+        let aabb = AABB::new(2.0, 2.0);
+
+        let res = circ.collision_resolution(Vec2::new(5.0, 0.8), &floor, Vec2::zero());
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.2, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, 1.0, abs <= 0.0001);
+
+        let res = floor.collision_resolution(
+            Transform::new(Vec2::new(3.0, 0.0), FRAC_PI_2, 1.0),
+            &aabb,
+            Vec2::new(2.5, 7.0),
+        );
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.5, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, 1.0, abs <= 0.0001);
+
+        assert!(!floor.collides(Vec2::zero(), &circ, Vec2::new(0.0, 1.1)));
+    }
+
+    #[test]
+    fn test_plane_resolution() {
+        let floor = HalfPlane::new(Vec2::new(0.0, 1.0), 0.0);
+        let ceiling = HalfPlane::new(Vec2::new(0.0, -1.0), 0.0);
+
+        let res = floor.collision_resolution(Vec2::zero(), &ceiling, Vec2::new(0.0, -0.5));
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.5, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, -1.0, abs <= 0.0001);
+        assert!(!floor.collides(Vec2::zero(), &ceiling, Vec2::new(0.0, 0.5)));
+
+        let wall = Transform::new(Vec2::new(3.0, 0.0), FRAC_PI_2, 1.0);
+        for transform in [wall, Transform::from(Vec2::new(0.0, 10.0))] {
+            let res = floor.collision_resolution(Vec2::zero(), &floor, transform);
+            assert!(res.colliding);
+            assert_float_eq!(res.penetration, 0.0, abs <= 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let floor = HalfPlane::new(Vec2::new(0.0, 1.0), 0.0);
+
+        let bounds = floor.bounding_box(Vec2::new(0.0, 2.0));
+        assert_eq!(bounds.min.x, f64::NEG_INFINITY);
+        assert_eq!(bounds.min.y, f64::NEG_INFINITY);
+        assert_eq!(bounds.max.x, f64::INFINITY);
+        assert_float_eq!(bounds.max.y, 2.0, abs <= 0.0001);
+
+        let bounds = floor.bounding_box(Transform::new(Vec2::zero(), 0.5, 1.0));
+        assert_eq!(bounds.max.y, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_raycast() {
+        let floor = HalfPlane::new(Vec2::new(0.0, 1.0), 0.0);
+
+        let hit = floor
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(3.0, 4.0),
+                Vec2::new(0.0, -2.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 2.0, abs <= 0.0001);
+        assert_float_eq!(hit.normal.y, 1.0, abs <= 0.0001);
+
+        assert_float_eq!(
+            floor
+                .raycast(
+                    Vec2::zero(),
+                    Vec2::new(3.0, -4.0),
+                    Vec2::new(0.0, 1.0),
+                    10.0
+                )
+                .unwrap()
+                .toi,
+            0.0,
+            abs <= 0.0001
+        );
+        assert!(floor
+            .raycast(Vec2::zero(), Vec2::new(3.0, 4.0), Vec2::new(1.0, 0.0), 10.0)
+            .is_none());
+        assert!(floor
+            .raycast(Vec2::zero(), Vec2::new(3.0, 4.0), Vec2::new(0.0, -1.0), 3.0)
+            .is_none());
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(HalfPlane::try_new(Vec2::new(0.0, 1.0), 0.0).is_ok());
+        assert!(matches!(
+            HalfPlane::try_new(Vec2::zero(), 1.0),
+            Err(ShapeError::DegenerateGeometry)
+        ));
+        assert!(matches!(
+            HalfPlane::try_new(Vec2::new(0.0, 1.0), f64::NAN),
+            Err(ShapeError::NonFinite)
+        ));
+    }
+}
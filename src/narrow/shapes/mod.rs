@@ -1,27 +1,79 @@
-use crate::NumTolerance;
+use crate::{vec2::Vec2, NumTolerance};
 
 #[cfg(test)]
-use crate::{narrow::sat::Axis, vec2::Vec2};
+use crate::narrow::sat::Axis;
 
 pub mod aabb;
 pub mod capsule;
+pub mod chain;
 pub mod circle;
+pub mod compound;
+pub mod concave;
+pub mod ellipse;
+pub mod half_plane;
+pub mod obb;
 pub mod p_gram;
 pub mod polygon;
+pub mod rounded_polygon;
+pub mod segment;
+pub mod shape;
 pub mod triangle;
 
+#[derive(Clone, Copy)]
 pub enum ShapeType<'a, T: NumTolerance> {
     AABB(&'a aabb::AABB<T>),
     Capsule(&'a capsule::Capsule<T>),
+    Chain(&'a chain::Chain<T>),
     Circle(&'a circle::Circle<T>),
+    Compound(&'a compound::Compound<T>),
+    Concave(&'a concave::ConcavePolygon<T>),
+    Ellipse(&'a ellipse::Ellipse<T>),
+    HalfPlane(&'a half_plane::HalfPlane<T>),
+    OBB(&'a obb::OBB<T>),
     Pgram(&'a p_gram::Pgram<T>),
     Polygon(&'a polygon::Polygon<T>),
+    RoundedPolygon(&'a rounded_polygon::RoundedPolygon<T>),
+    Segment(&'a segment::Segment<T>),
     Triangle(&'a triangle::Triangle<T>),
     None,
 }
 
+#[derive(Debug)]
+pub enum ShapeError {
+    NegativeSize,
+    ZeroSize,
+    NonFinite,
+    DegenerateGeometry,
+}
+
+pub(crate) fn check_finite<T: NumTolerance>(vectors: &[Vec2<T>]) -> Result<(), ShapeError> {
+    match vectors
+        .iter()
+        .all(|vector| vector.x.is_finite() && vector.y.is_finite())
+    {
+        true => Ok(()),
+        false => Err(ShapeError::NonFinite),
+    }
+}
+
+pub(crate) fn check_size<T: NumTolerance>(size: T) -> Result<(), ShapeError> {
+    if !size.is_finite() {
+        return Err(ShapeError::NonFinite);
+    }
+
+    // Any nonzero size is a real shape, however small
+    if size.is_zero() {
+        return Err(ShapeError::ZeroSize);
+    }
+
+    match size.is_sign_negative() {
+        true => Err(ShapeError::NegativeSize),
+        false => Ok(()),
+    }
+}
+
 pub trait Shapeable<T: NumTolerance> {
-    fn shape(&self) -> ShapeType<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
         ShapeType::None
     }
 }
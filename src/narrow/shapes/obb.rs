@@ -0,0 +1,285 @@
+use crate::{
+    narrow::{
+        gjk::Supportable,
+        ray::{raycast_local, raycast_vertices, RayHit, Raycastable},
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+        shapes::{ShapeType, Shapeable},
+    },
+    transform::Transform,
+    utility::{closest_vertex, project_onto, support_vertex, Projection},
+    vec2::Vec2,
+    NumTolerance,
+};
+
+use super::aabb::AABB;
+
+pub struct OBB<T: NumTolerance> {
+    half_extents: Vec2<T>,
+    rotation: Vec2<T>,
+}
+
+struct WorldBox<T: NumTolerance> {
+    center: Vec2<T>,
+    axes: [Vec2<T>; 2],
+    half_extents: [T; 2],
+}
+
+impl<T: NumTolerance> WorldBox<T> {
+    fn radius(&self, axis: Vec2<T>) -> T {
+        self.half_extents[0] * self.axes[0].dot(axis).abs()
+            + self.half_extents[1] * self.axes[1].dot(axis).abs()
+    }
+}
+
+impl<T: NumTolerance> OBB<T> {
+    pub fn new(half_extents: Vec2<T>, angle: T) -> Self {
+        Self {
+            half_extents,
+            rotation: Vec2::new(angle.cos(), angle.sin()),
+        }
+    }
+
+    pub fn half_extents(&self) -> Vec2<T> {
+        self.half_extents
+    }
+
+    pub fn local_axes(&self) -> [Vec2<T>; 2] {
+        [self.rotation, self.rotation.rotate_counter_90()]
+    }
+
+    pub fn vertices(&self) -> [Vec2<T>; 4] {
+        let [u, v] = self.local_axes();
+        let u = u.scale(self.half_extents.x);
+        let v = v.scale(self.half_extents.y);
+
+        [-u - v, u - v, u + v, v - u]
+    }
+
+    fn world_box(&self, transform: Transform<T>) -> WorldBox<T> {
+        let [u, v] = self.local_axes();
+
+        WorldBox {
+            center: transform.translation,
+            axes: [transform.rotate(u), transform.rotate(v)],
+            half_extents: [
+                self.half_extents.x * transform.scale(),
+                self.half_extents.y * transform.scale(),
+            ],
+        }
+    }
+
+    pub fn obb_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &OBB<T>,
+        shape_transform: impl Into<Transform<T>>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        box_resolution(
+            self.world_box(transform.into()),
+            shape.world_box(shape_transform.into()),
+            resolve,
+        )
+    }
+
+    pub fn aabb_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &AABB<T>,
+        shape_transform: impl Into<Transform<T>>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        let two = T::one() + T::one();
+        let shape_box = OBB::new(
+            Vec2::new(shape.width() / two, shape.height() / two),
+            T::zero(),
+        );
+
+        self.obb_resolution(transform, &shape_box, shape_transform, resolve)
+    }
+}
+
+// Two boxes can only be separated along one of their four face normals
+fn box_resolution<T: NumTolerance>(
+    actor: WorldBox<T>,
+    pushed: WorldBox<T>,
+    resolve: bool,
+) -> Resolution<T> {
+    let mut resolution = Resolution::new();
+    let direction = actor.center - pushed.center;
+
+    for axis in actor.axes.into_iter().chain(pushed.axes) {
+        let reach = actor.radius(axis) + pushed.radius(axis);
+        let distance = direction.dot(axis);
+
+        if reach.is_difference_small(distance.abs()) {
+            return resolution;
+        }
+
+        let penetration = reach - distance.abs();
+        if resolve && penetration < resolution.penetration {
+            resolution.penetration = penetration;
+            resolution.axis = match distance.is_sign_negative() {
+                true => -axis,
+                false => axis,
+            };
+        }
+    }
+
+    resolution.colliding = true;
+
+    resolution
+}
+
+impl<T: NumTolerance> SATable<T> for OBB<T> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        let transform = transform.into();
+
+        self.local_axes().into_iter().map(move |axis| Axis::Static {
+            vector: transform.rotate(axis),
+            normalized: true,
+        })
+    }
+
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        project_onto(transform, &self.vertices(), axis)
+    }
+
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        closest_vertex(point, transform, &self.vertices()) - point
+    }
+
+    fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
+        let local = transform.into().inverse_apply(point);
+        let [u, v] = self.local_axes();
+
+        if local.dot(u).abs() > self.half_extents.x {
+            return false;
+        }
+
+        local.dot(v).abs() <= self.half_extents.y
+    }
+
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
+    }
+
+    fn collision_resolution<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T>
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Supportable<T> for OBB<T> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T> {
+        support_vertex(transform, &self.vertices(), direction)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for OBB<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        raycast_local(
+            transform,
+            origin,
+            direction,
+            max_toi,
+            |origin, direction, max_toi| {
+                raycast_vertices(&self.vertices(), origin, direction, max_toi)
+            },
+        )
+    }
+}
+
+impl<T: NumTolerance> Shapeable<T> for OBB<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
+        ShapeType::OBB(self)
+    }
+}
+
+#[cfg(test)]
+mod obb_tests {
+
+    use float_eq::assert_float_eq;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use super::OBB;
+    use crate::{
+        narrow::{sat::SATable, shapes::contains_perpendicular},
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_vertices() {
+        let box0 = OBB::new(Vec2::new(2.0, 1.0), 0.0);
+        let box1 = OBB::new(Vec2::new(2.0, 1.0), FRAC_PI_2);
+
+        let vertices = box0.vertices();
+        assert_float_eq!(vertices[0].x, -2.0, abs <= 0.01);
+        assert_float_eq!(vertices[0].y, -1.0, abs <= 0.01);
+        assert_float_eq!(vertices[2].x, 2.0, abs <= 0.01);
+        assert_float_eq!(vertices[2].y, 1.0, abs <= 0.01);
+
+        let vertices = box1.vertices();
+        assert_float_eq!(vertices[0].x, 1.0, abs <= 0.01);
+        assert_float_eq!(vertices[0].y, -2.0, abs <= 0.01);
+        assert_float_eq!(vertices[1].x, 1.0, abs <= 0.01);
+        assert_float_eq!(vertices[1].y, 2.0, abs <= 0.01);
+        assert_float_eq!(vertices[2].x, -1.0, abs <= 0.01);
+        assert_float_eq!(vertices[2].y, 2.0, abs <= 0.01);
+    }
+
+    #[test]
+    fn test_axes() {
+        let diamond = OBB::new(Vec2::new(1.0, 1.0), FRAC_PI_4);
+
+        assert!(contains_perpendicular(
+            diamond.axes(Vec2::zero()),
+            Vec2::new(1.0, 1.0)
+        ));
+        assert!(contains_perpendicular(
+            diamond.axes(Vec2::zero()),
+            Vec2::new(1.0, -1.0)
+        ));
+        assert!(!contains_perpendicular(
+            diamond.axes(Vec2::zero()),
+            Vec2::new(1.0, 0.0)
+        ));
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let diamond = OBB::new(Vec2::new(1.0, 1.0), FRAC_PI_4);
+        let upright = OBB::new(Vec2::new(2.0, 0.5), FRAC_PI_2);
+
+        assert!(diamond.contains_point(Vec2::zero(), Vec2::new(1.3, 0.0)));
+        assert!(diamond.contains_point(Vec2::new(1.0, 1.0), Vec2::new(1.0, -0.3)));
+        assert!(!diamond.contains_point(Vec2::zero(), Vec2::new(0.9, 0.9)));
+        assert!(!diamond.contains_point(Vec2::new(1.0, 1.0), Vec2::new(2.5, 1.0)));
+
+        assert!(upright.contains_point(Vec2::zero(), Vec2::new(0.4, 1.9)));
+        assert!(upright.contains_point(Vec2::new(-2.0, 0.0), Vec2::new(-2.2, -1.5)));
+        assert!(!upright.contains_point(Vec2::zero(), Vec2::new(1.0, 0.0)));
+        assert!(!upright.contains_point(Vec2::new(-2.0, 0.0), Vec2::new(-2.0, 2.1)));
+    }
+}
@@ -1,11 +1,16 @@
 use crate::{
-    narrow::sat::{Axis, Resolution, SATable},
-    utility::{closest_vertex, project_onto, Projection},
+    narrow::{
+        gjk::Supportable,
+        ray::{raycast_local, raycast_vertices, RayHit, Raycastable},
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+    },
+    transform::Transform,
+    utility::{closest_vertex, project_onto, support_vertex, Projection},
     vec2::Vec2,
     NumTolerance,
 };
 
-use super::{ShapeType, Shapeable};
+use super::{check_finite, polygon::is_straight, ShapeError, ShapeType, Shapeable};
 
 pub struct Pgram<T: NumTolerance> {
     u: Vec2<T>,
@@ -17,6 +22,20 @@ impl<T: NumTolerance> Pgram<T> {
         Self { u, v }
     }
 
+    pub fn try_new(u: Vec2<T>, v: Vec2<T>) -> Result<Self, ShapeError> {
+        check_finite(&[u, v])?;
+
+        if u.length_squared().is_zero() || v.length_squared().is_zero() {
+            return Err(ShapeError::ZeroSize);
+        }
+
+        if is_straight(u, v) {
+            return Err(ShapeError::DegenerateGeometry);
+        }
+
+        Ok(Self::new(u, v))
+    }
+
     pub fn vertices(&self) -> [Vec2<T>; 4] {
         let half = T::one() / (T::one() + T::one());
         let sum = self.u + self.v;
@@ -32,70 +51,101 @@ impl<T: NumTolerance> Pgram<T> {
 }
 
 impl<T: NumTolerance> SATable<T> for Pgram<T> {
-    fn axes(&self) -> impl Iterator<Item = Axis<T>> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        let transform = transform.into();
+
         [
             Axis::Static {
-                vector: self.u.rotate_counter_90(),
+                vector: transform.rotate(self.u.rotate_counter_90()),
                 normalized: false,
             },
             Axis::Static {
-                vector: self.v.rotate_counter_90(),
+                vector: transform.rotate(self.v.rotate_counter_90()),
                 normalized: false,
             },
         ]
         .into_iter()
     }
 
-    fn project(&self, axis: Vec2<T>, position: Vec2<T>) -> Projection<T> {
-        project_onto(position, &self.vertices(), axis)
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        project_onto(transform, &self.vertices(), axis)
     }
 
-    fn axis_from_point(&self, position: Vec2<T>, point: Vec2<T>) -> Vec2<T> {
-        closest_vertex(point, position, &self.vertices()) - point
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        closest_vertex(point, transform, &self.vertices()) - point
     }
 
-    fn contains_point(&self, position: Vec2<T>, point: Vec2<T>) -> bool {
-        let axis = self.u.rotate_counter_90();
+    fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
+        let transform = transform.into();
+
+        let axis = transform.rotate(self.u.rotate_counter_90());
         let proj = point.dot(axis);
-        let self_proj = self.project(axis, position);
+        let self_proj = self.project(axis, transform);
 
         if !proj.is_between(self_proj.min, self_proj.max) {
             return false;
         }
 
-        let axis = self.v.rotate_counter_90();
+        let axis = transform.rotate(self.v.rotate_counter_90());
         let proj = point.dot(axis);
-        let self_proj = self.project(axis, position);
+        let self_proj = self.project(axis, transform);
 
         proj.is_between(self_proj.min, self_proj.max)
     }
 
-    fn collides<S>(&self, position: Vec2<T>, shape: &S, shape_position: Vec2<T>) -> bool
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
     where
         S: SATable<T> + Shapeable<T>,
     {
-        match shape.shape() {
-            _ => self.sat_collides(position, shape, shape_position),
-        }
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
     }
 
     fn collision_resolution<S>(
         &self,
-        position: Vec2<T>,
+        transform: impl Into<Transform<T>>,
         shape: &S,
-        shape_position: Vec2<T>,
+        shape_transform: impl Into<Transform<T>>,
     ) -> Resolution<T>
     where
         S: SATable<T> + Shapeable<T>,
     {
-        match shape.shape() {
-            _ => self.sat_collision_resolution(position, shape, shape_position),
-        }
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Supportable<T> for Pgram<T> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T> {
+        support_vertex(transform, &self.vertices(), direction)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for Pgram<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        raycast_local(
+            transform,
+            origin,
+            direction,
+            max_toi,
+            |origin, direction, max_toi| {
+                raycast_vertices(&self.vertices(), origin, direction, max_toi)
+            },
+        )
     }
 }
 
 impl<T: NumTolerance> Shapeable<T> for Pgram<T> {
-    fn shape(&self) -> ShapeType<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
         ShapeType::Pgram(self)
     }
 }
@@ -105,7 +155,7 @@ mod aabb_tests {
 
     use float_eq::assert_float_eq;
 
-    use super::Pgram;
+    use super::{Pgram, ShapeError};
     use crate::{narrow::sat::SATable, vec2::Vec2};
 
     #[test]
@@ -149,4 +199,22 @@ mod aabb_tests {
         assert!(!gram1.contains_point(Vec2::new(20.0, 20.0), Vec2::new(-1.0, 0.5)));
         assert!(!gram1.contains_point(Vec2::new(0.0, 0.0), Vec2::new(6.0, 1.0)));
     }
+
+    #[test]
+    fn test_try_new() {
+        assert!(Pgram::try_new(Vec2::new(2.0, 0.0), Vec2::new(1.0, 2.0)).is_ok());
+        assert!(matches!(
+            Pgram::try_new(Vec2::new(2.0, 0.0), Vec2::new(-1.0, 0.0)),
+            Err(ShapeError::DegenerateGeometry)
+        ));
+        assert!(matches!(
+            Pgram::try_new(Vec2::zero(), Vec2::new(1.0, 2.0)),
+            Err(ShapeError::ZeroSize)
+        ));
+        assert!(Pgram::try_new(Vec2::new(0.001, 0.0), Vec2::new(0.0, 0.001)).is_ok());
+        assert!(matches!(
+            Pgram::try_new(Vec2::new(2.0, 0.0), Vec2::new(1.0, f64::NAN)),
+            Err(ShapeError::NonFinite)
+        ));
+    }
 }
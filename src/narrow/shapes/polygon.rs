@@ -1,11 +1,16 @@
 use crate::{
-    narrow::sat::{Axis, SATable},
-    utility::{closest_vertex, project_onto, Projection},
+    narrow::{
+        gjk::Supportable,
+        ray::{raycast_local, raycast_vertices, RayHit, Raycastable},
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+    },
+    transform::Transform,
+    utility::{closest_vertex, project_onto, support_vertex, Projection},
     vec2::Vec2,
     NumTolerance,
 };
 
-use super::{ShapeType, Shapeable};
+use super::{check_finite, ShapeError, ShapeType, Shapeable};
 
 pub struct Polygon<T: NumTolerance> {
     vertices: Vec<Vec2<T>>,
@@ -15,6 +20,54 @@ pub struct Polygon<T: NumTolerance> {
 pub enum PolygonError {
     TooFewVerticesError,
     ConcaveError,
+    DecompositionError,
+    SelfIntersecting,
+    DegenerateEdge,
+    ZeroArea,
+    CollinearVertices,
+    NonFinite,
+    InvalidRadius(ShapeError),
+}
+
+// Twice the enclosed area, positive when the outline winds counter-clockwise
+pub(crate) fn signed_area<T: NumTolerance>(vertices: &[Vec2<T>]) -> T {
+    (0..vertices.len())
+        .map(|i| vertices[i].cross(vertices[(i + 1) % vertices.len()]))
+        .fold(T::zero(), |area, cross| area + cross)
+}
+
+// Whether the turn from `edge` onto `next` is too slight to tell apart from a straight line, whatever the edges' lengths
+pub(crate) fn is_straight<T: NumTolerance>(edge: Vec2<T>, next: Vec2<T>) -> bool {
+    let scale = edge.length() * next.length();
+
+    scale.is_zero() || (edge.cross(next) / scale).is_trivial_abs()
+}
+
+// Checks every pair of edges that don't share a vertex for a crossing
+pub(crate) fn self_intersecting<T: NumTolerance>(vertices: &[Vec2<T>]) -> bool {
+    let len = vertices.len();
+    let edge = |i: usize| (vertices[i], vertices[(i + 1) % len]);
+
+    (0..len).any(|i| {
+        ((i + 2)..len)
+            .filter(|&j| (j + 1) % len != i)
+            .any(|j| crosses(edge(i), edge(j)))
+    })
+}
+
+fn crosses<T: NumTolerance>((a, b): (Vec2<T>, Vec2<T>), (c, d): (Vec2<T>, Vec2<T>)) -> bool {
+    let sides = |start: Vec2<T>, end: Vec2<T>, p: Vec2<T>, q: Vec2<T>| {
+        let (p_side, q_side) = (
+            (end - start).cross(p - start),
+            (end - start).cross(q - start),
+        );
+
+        !is_straight(end - start, p - start)
+            && !is_straight(end - start, q - start)
+            && p_side.is_sign_positive() != q_side.is_sign_positive()
+    };
+
+    sides(a, b, c, d) && sides(c, d, a, b)
 }
 
 fn concave<T: NumTolerance>(vertices: &[Vec2<T>]) -> bool {
@@ -36,38 +89,134 @@ fn concave<T: NumTolerance>(vertices: &[Vec2<T>]) -> bool {
         }
     }
 
-    return false;
+    false
 }
 
 impl<T: NumTolerance> Polygon<T> {
     pub fn new(vertices: Vec<Vec2<T>>) -> Result<Polygon<T>, PolygonError> {
+        check_finite(&vertices).map_err(|_| PolygonError::NonFinite)?;
+
         if vertices.len() <= 2 {
             return Err(PolygonError::TooFewVerticesError);
         }
 
+        let len = vertices.len();
+        let edge = |i: usize| vertices[(i + 1) % len] - vertices[i];
+
+        // Lengths and areas are measured against the longest edge, so the checks hold at any scale
+        let size = (0..len).fold(T::zero(), |size, i| T::max(size, edge(i).length()));
+        if size.is_zero() || (0..len).any(|i| (edge(i).length() / size).is_trivial_abs()) {
+            return Err(PolygonError::DegenerateEdge);
+        }
+
+        if (signed_area(&vertices) / (size * size)).is_trivial_abs() {
+            return Err(PolygonError::ZeroArea);
+        }
+
+        if (0..len).any(|i| is_straight(edge(i), edge((i + 1) % len))) {
+            return Err(PolygonError::CollinearVertices);
+        }
+
         if concave(&vertices) {
             return Err(PolygonError::ConcaveError);
         }
 
+        if self_intersecting(&vertices) {
+            return Err(PolygonError::SelfIntersecting);
+        }
+
         Ok(Polygon { vertices })
     }
 
+    // Same checks as `new`, but clockwise outlines are reversed to wind counter-clockwise
+    pub fn new_counter_clockwise(mut vertices: Vec<Vec2<T>>) -> Result<Polygon<T>, PolygonError> {
+        if signed_area(&vertices).is_sign_negative() {
+            vertices.reverse();
+        }
+
+        Polygon::new(vertices)
+    }
+
+    // Same checks as `new`, but vertices partway along a straight edge are dropped rather than rejected
+    pub fn new_without_collinear(vertices: Vec<Vec2<T>>) -> Result<Polygon<T>, PolygonError> {
+        Polygon::new(without_straight(vertices))
+    }
+
+    // Andrew's monotone chain, wound counter-clockwise without repeated or collinear points
+    pub fn from_hull(mut points: Vec<Vec2<T>>) -> Result<Polygon<T>, PolygonError> {
+        check_finite(&points).map_err(|_| PolygonError::NonFinite)?;
+
+        points.sort_by(|a, b| {
+            a.x.partial_cmp(&b.x)
+                .unwrap()
+                .then(a.y.partial_cmp(&b.y).unwrap())
+        });
+
+        let mut lower = half_hull(points.iter());
+        let mut upper = half_hull(points.iter().rev());
+
+        // Each half ends where the other starts
+        lower.pop();
+        upper.pop();
+        lower.append(&mut upper);
+
+        Polygon::new(lower)
+    }
+
     pub fn vertices(&self) -> &[Vec2<T>] {
         &self.vertices
     }
 }
 
+// Drops vertices that carry an edge straight on, which leaves the outline unchanged
+fn without_straight<T: NumTolerance>(vertices: Vec<Vec2<T>>) -> Vec<Vec2<T>> {
+    let len = vertices.len();
+
+    (0..len)
+        .filter(|&i| {
+            let (prev, next) = (vertices[(i + len - 1) % len], vertices[(i + 1) % len]);
+            let (edge, next_edge) = (vertices[i] - prev, next - vertices[i]);
+
+            !is_straight(edge, next_edge) || edge.dot(next_edge) <= T::zero()
+        })
+        .map(|i| vertices[i])
+        .collect()
+}
+
+fn half_hull<'a, T: NumTolerance + 'a>(points: impl Iterator<Item = &'a Vec2<T>>) -> Vec<Vec2<T>> {
+    let mut hull: Vec<Vec2<T>> = Vec::new();
+
+    for &point in points {
+        while hull.len() >= 2 {
+            let (edge, next) = (
+                hull[hull.len() - 1] - hull[hull.len() - 2],
+                point - hull[hull.len() - 1],
+            );
+            if edge.cross(next) > T::zero() && !is_straight(edge, next) {
+                break;
+            }
+            hull.pop();
+        }
+        hull.push(point);
+    }
+
+    hull
+}
+
 impl<T: NumTolerance> SATable<T> for Polygon<T> {
-    fn axes(&self) -> impl Iterator<Item = Axis<T>> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        let transform = transform.into();
         let mut axes = Vec::new();
 
         axes.push(Axis::Static {
-            vector: (self.vertices[0] - *self.vertices.last().unwrap()).rotate_counter_90(),
+            vector: transform
+                .rotate((self.vertices[0] - *self.vertices.last().unwrap()).rotate_counter_90()),
             normalized: false,
         });
         for i in 1..self.vertices.len() {
             axes.push(Axis::Static {
-                vector: (self.vertices[i] - self.vertices[i - 1]).rotate_counter_90(),
+                vector: transform
+                    .rotate((self.vertices[i] - self.vertices[i - 1]).rotate_counter_90()),
                 normalized: false,
             });
         }
@@ -75,17 +224,67 @@ impl<T: NumTolerance> SATable<T> for Polygon<T> {
         axes.into_iter()
     }
 
-    fn project(&self, axis: Vec2<T>, position: Vec2<T>) -> Projection<T> {
-        project_onto(position, &self.vertices(), axis)
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        project_onto(transform, self.vertices(), axis)
+    }
+
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        closest_vertex(point, transform, self.vertices()) - point
     }
 
-    fn axis_from_point(&self, position: Vec2<T>, point: Vec2<T>) -> Vec2<T> {
-        closest_vertex(point, position, &self.vertices()) - point
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
+    }
+
+    fn collision_resolution<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T>
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Supportable<T> for Polygon<T> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T> {
+        support_vertex(transform, self.vertices(), direction)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for Polygon<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        raycast_local(
+            transform,
+            origin,
+            direction,
+            max_toi,
+            |origin, direction, max_toi| {
+                raycast_vertices(self.vertices(), origin, direction, max_toi)
+            },
+        )
     }
 }
 
 impl<T: NumTolerance> Shapeable<T> for Polygon<T> {
-    fn shape(&self) -> ShapeType<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
         ShapeType::Polygon(self)
     }
 }
@@ -93,26 +292,29 @@ impl<T: NumTolerance> Shapeable<T> for Polygon<T> {
 #[cfg(test)]
 mod triangle_tests {
 
+    use float_eq::assert_float_eq;
+    use std::f64::consts::TAU;
+
     use crate::{
         narrow::{sat::SATable, shapes::contains_perpendicular},
         vec2::Vec2,
     };
 
-    use super::{concave, Polygon};
+    use super::{concave, signed_area, Polygon, PolygonError};
 
     #[test]
     fn test_concave() {
-        assert!(!concave(&vec![
+        assert!(!concave(&[
             Vec2::new(0.0, 0.0),
             Vec2::new(1.0, 0.0),
             Vec2::new(0.0, 1.0)
         ]));
-        assert!(!concave(&vec![
+        assert!(!concave(&[
             Vec2::new(0.0, 0.0),
             Vec2::new(0.0, 1.0),
             Vec2::new(1.0, 0.0)
         ]));
-        assert!(!concave(&vec![
+        assert!(!concave(&[
             Vec2::new(0.0, 0.0),
             Vec2::new(1.0, 0.0),
             Vec2::new(1.0, 1.0),
@@ -120,19 +322,19 @@ mod triangle_tests {
             Vec2::new(0.0, 1.0)
         ]));
 
-        assert!(concave(&vec![
+        assert!(concave(&[
             Vec2::new(0.0, 0.0),
             Vec2::new(1.0, 0.0),
             Vec2::new(0.0, 1.0),
             Vec2::new(1.0, 1.0)
         ]));
-        assert!(concave(&vec![
+        assert!(concave(&[
             Vec2::new(0.0, 0.0),
             Vec2::new(0.0, 1.0),
             Vec2::new(1.0, 0.0),
             Vec2::new(2.0, 0.0)
         ]));
-        assert!(concave(&vec![
+        assert!(concave(&[
             Vec2::new(0.0, 0.0),
             Vec2::new(1.0, 0.0),
             Vec2::new(0.5, 2.0),
@@ -166,6 +368,163 @@ mod triangle_tests {
         assert!(concave.is_err());
     }
 
+    #[test]
+    fn test_validation() {
+        let repeated = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0),
+        ]);
+        let flat = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 2.0),
+        ]);
+        let collinear = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(0.0, 1.0),
+        ]);
+        // Every turn agrees, but the outline winds around twice
+        let star = Polygon::new(vec![
+            Vec2::new(0.0, 1.0),
+            Vec2::new(0.5878, -0.809),
+            Vec2::new(-0.9511, 0.309),
+            Vec2::new(0.9511, 0.309),
+            Vec2::new(-0.5878, -0.809),
+        ]);
+
+        assert!(matches!(repeated, Err(PolygonError::DegenerateEdge)));
+        assert!(matches!(flat, Err(PolygonError::ZeroArea)));
+        assert!(matches!(collinear, Err(PolygonError::CollinearVertices)));
+        assert!(matches!(star, Err(PolygonError::SelfIntersecting)));
+
+        let nan = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(f64::NAN, 0.0),
+            Vec2::new(0.0, 1.0),
+        ]);
+        assert!(matches!(nan, Err(PolygonError::NonFinite)));
+
+        // Neither fine detail nor a small scale is mistaken for a degenerate outline
+        let round = (0..200)
+            .map(|i| {
+                let angle = i as f64 * TAU / 200.0;
+                Vec2::new(angle.cos(), angle.sin())
+            })
+            .collect();
+        assert!(Polygon::new(round).is_ok());
+
+        let small = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.001, 0.0),
+            Vec2::new(0.0, 0.001),
+        ]);
+        assert!(small.is_ok());
+    }
+
+    #[test]
+    fn test_new_counter_clockwise() {
+        let clockwise = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 0.0),
+        ];
+
+        let square = Polygon::new_counter_clockwise(clockwise.clone()).unwrap();
+        assert!(signed_area(square.vertices()) > 0.0);
+        assert!(signed_area(Polygon::new(clockwise).unwrap().vertices()) < 0.0);
+    }
+
+    #[test]
+    fn test_new_without_collinear() {
+        let square = Polygon::new_without_collinear(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.5, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(0.0, 0.5),
+        ])
+        .unwrap();
+        assert_eq!(square.vertices().len(), 4);
+
+        let repeated = Polygon::new_without_collinear(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0),
+        ]);
+        assert!(matches!(repeated, Err(PolygonError::DegenerateEdge)));
+    }
+
+    #[test]
+    fn test_from_hull() {
+        let hull = Polygon::from_hull(vec![
+            Vec2::new(0.5, 0.5),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.5, 0.0),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 0.5),
+            Vec2::new(0.2, 0.7),
+        ])
+        .unwrap();
+
+        let expected = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ];
+        assert_eq!(hull.vertices().len(), expected.len());
+        for (vertex, expected) in hull.vertices().iter().zip(expected) {
+            assert_float_eq!(vertex.x, expected.x, abs <= 0.0001);
+            assert_float_eq!(vertex.y, expected.y, abs <= 0.0001);
+        }
+
+        let collinear = Polygon::from_hull(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(1.0, 1.0),
+        ]);
+        let repeated = Polygon::from_hull(vec![Vec2::new(1.0, 2.0); 5]);
+
+        assert!(collinear.is_err());
+        assert!(repeated.is_err());
+        assert!(Polygon::<f64>::from_hull(Vec::new()).is_err());
+
+        let nan = Polygon::from_hull(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(f64::NAN, 1.0),
+            Vec2::new(1.0, 0.0),
+        ]);
+        assert!(matches!(nan, Err(PolygonError::NonFinite)));
+
+        // Dense and small clouds keep every corner
+        let dense = (0..1000)
+            .map(|i| {
+                let angle = i as f64 * TAU / 1000.0;
+                Vec2::new(angle.cos(), angle.sin())
+            })
+            .collect();
+        assert_eq!(Polygon::from_hull(dense).unwrap().vertices().len(), 1000);
+
+        let small = Polygon::from_hull(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.005, 0.0),
+            Vec2::new(0.005, 0.005),
+            Vec2::new(0.0, 0.005),
+        ]);
+        assert_eq!(small.unwrap().vertices().len(), 4);
+    }
+
     #[test]
     fn test_axes() {
         let triangle = Polygon::new(vec![
@@ -183,20 +542,35 @@ mod triangle_tests {
         ])
         .unwrap();
 
-        assert!(contains_perpendicular(triangle.axes(), Vec2::new(0.0, 1.0)));
-        assert!(contains_perpendicular(triangle.axes(), Vec2::new(1.0, 0.0)));
         assert!(contains_perpendicular(
-            triangle.axes(),
+            triangle.axes(Vec2::zero()),
+            Vec2::new(0.0, 1.0)
+        ));
+        assert!(contains_perpendicular(
+            triangle.axes(Vec2::zero()),
+            Vec2::new(1.0, 0.0)
+        ));
+        assert!(contains_perpendicular(
+            triangle.axes(Vec2::zero()),
             Vec2::new(1.0, -1.0)
         ));
 
-        assert!(contains_perpendicular(pentagon.axes(), Vec2::new(0.0, 1.0)));
-        assert!(contains_perpendicular(pentagon.axes(), Vec2::new(1.0, 0.0)));
         assert!(contains_perpendicular(
-            pentagon.axes(),
+            pentagon.axes(Vec2::zero()),
+            Vec2::new(0.0, 1.0)
+        ));
+        assert!(contains_perpendicular(
+            pentagon.axes(Vec2::zero()),
+            Vec2::new(1.0, 0.0)
+        ));
+        assert!(contains_perpendicular(
+            pentagon.axes(Vec2::zero()),
             Vec2::new(-0.5, 1.0)
         ));
-        assert!(contains_perpendicular(pentagon.axes(), Vec2::new(0.5, 1.0)))
+        assert!(contains_perpendicular(
+            pentagon.axes(Vec2::zero()),
+            Vec2::new(0.5, 1.0)
+        ))
     }
 
     #[test]
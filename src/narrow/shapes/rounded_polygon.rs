@@ -0,0 +1,328 @@
+use crate::{
+    narrow::{
+        gjk::Supportable,
+        ray::{raycast_circle, raycast_local, raycast_vertices, RayHit, Raycastable},
+        rounded::{rounded_resolution, RoundedFeature},
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+    },
+    transform::Transform,
+    utility::{closest_vertex, Projection},
+    vec2::Vec2,
+    NumTolerance,
+};
+
+use super::{
+    check_size,
+    polygon::{Polygon, PolygonError},
+    ShapeType, Shapeable,
+};
+
+// A convex polygon grown by a radius, the same way a capsule grows its path
+pub struct RoundedPolygon<T: NumTolerance> {
+    polygon: Polygon<T>,
+    radius: T,
+}
+
+impl<T: NumTolerance> RoundedPolygon<T> {
+    pub fn new(vertices: Vec<Vec2<T>>, radius: T) -> Result<RoundedPolygon<T>, PolygonError> {
+        check_size(radius).map_err(PolygonError::InvalidRadius)?;
+
+        Ok(Self {
+            polygon: Polygon::new(vertices)?,
+            radius,
+        })
+    }
+
+    // A box whose straight sides span `half_extents`, with the corners rounded outside of them
+    pub fn rounded_box(
+        half_extents: Vec2<T>,
+        radius: T,
+    ) -> Result<RoundedPolygon<T>, PolygonError> {
+        let (x, y) = (half_extents.x, half_extents.y);
+
+        Self::new(
+            vec![
+                Vec2::new(-x, -y),
+                Vec2::new(x, -y),
+                Vec2::new(x, y),
+                Vec2::new(-x, y),
+            ],
+            radius,
+        )
+    }
+
+    pub fn vertices(&self) -> &[Vec2<T>] {
+        self.polygon.vertices()
+    }
+
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    pub(crate) fn feature_resolution(
+        &self,
+        transform: Transform<T>,
+        feature: &RoundedFeature<T>,
+        resolve: bool,
+    ) -> Resolution<T> {
+        let core: Vec<Vec2<T>> = self
+            .vertices()
+            .iter()
+            .map(|vertex| transform.apply(*vertex))
+            .collect();
+
+        rounded_resolution(
+            &core,
+            self.radius * transform.scale(),
+            &feature.vertices,
+            feature.radius,
+            resolve,
+        )
+    }
+}
+
+impl<T: NumTolerance> SATable<T> for RoundedPolygon<T> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        let transform = transform.into();
+
+        // The straight sides keep the polygon's normals, and each corner arc needs an axis towards the other shape
+        self.polygon
+            .axes(transform)
+            .chain(self.vertices().iter().map(move |vertex| Axis::Dynamic {
+                point: transform.apply(*vertex),
+            }))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        let transform = transform.into();
+        let proj = self.polygon.project(axis, transform);
+        let radius = self.radius * transform.scale() * axis.length();
+
+        Projection {
+            min: proj.min - radius,
+            max: proj.max + radius,
+        }
+    }
+
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        closest_vertex(point, transform, self.vertices()) - point
+    }
+
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
+    }
+
+    fn collision_resolution<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T>
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Supportable<T> for RoundedPolygon<T> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T> {
+        let transform = transform.into();
+        let vertex = self.polygon.support(transform, direction);
+
+        let length = direction.length();
+        if length.is_trivial_abs() {
+            return vertex;
+        }
+
+        vertex + direction.scale(self.radius * transform.scale() / length)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for RoundedPolygon<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        raycast_local(
+            transform,
+            origin,
+            direction,
+            max_toi,
+            |origin, direction, max_toi| {
+                let vertices = self.vertices();
+
+                // The rounded polygon is the union of its core, a slab along each side and a circle at each corner
+                let slabs = (0..vertices.len()).map(|i| {
+                    let (start, end) = (vertices[i], vertices[(i + 1) % vertices.len()]);
+                    let side = (end - start)
+                        .rotate_counter_90()
+                        .normalized()
+                        .scale(self.radius);
+
+                    raycast_vertices(
+                        &[start - side, end - side, end + side, start + side],
+                        origin,
+                        direction,
+                        max_toi,
+                    )
+                });
+                let corners = vertices
+                    .iter()
+                    .map(|vertex| raycast_circle(*vertex, self.radius, origin, direction, max_toi));
+
+                [raycast_vertices(vertices, origin, direction, max_toi)]
+                    .into_iter()
+                    .chain(slabs)
+                    .chain(corners)
+                    .flatten()
+                    .min_by(|(toi, _), (other_toi, _)| toi.partial_cmp(other_toi).unwrap())
+            },
+        )
+    }
+}
+
+impl<T: NumTolerance> Shapeable<T> for RoundedPolygon<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
+        ShapeType::RoundedPolygon(self)
+    }
+}
+
+#[cfg(test)]
+mod rounded_polygon_tests {
+
+    use float_eq::assert_float_eq;
+    use std::f64::consts::FRAC_PI_4;
+
+    use super::RoundedPolygon;
+    use crate::{
+        narrow::{
+            gjk::Supportable,
+            ray::Raycastable,
+            sat::SATable,
+            shapes::{aabb::AABB, circle::Circle, polygon::PolygonError, ShapeError},
+        },
+        transform::Transform,
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_new() {
+        assert!(RoundedPolygon::rounded_box(Vec2::new(0.005, 0.002), 0.001).is_ok());
+        assert!(matches!(
+            RoundedPolygon::rounded_box(Vec2::new(1.0, 1.0), -0.5),
+            Err(PolygonError::InvalidRadius(ShapeError::NegativeSize))
+        ));
+        assert!(matches!(
+            RoundedPolygon::rounded_box(Vec2::new(1.0, 1.0), f64::NAN),
+            Err(PolygonError::InvalidRadius(ShapeError::NonFinite))
+        ));
+    }
+
+    #[test]
+    fn test_project() {
+        let rounded = RoundedPolygon::rounded_box(Vec2::new(1.0, 0.5), 0.25).unwrap();
+
+        let proj = rounded.project(Vec2::new(1.0, 0.0), Vec2::new(1.0, 0.0));
+        assert_float_eq!(proj.min, -0.25, abs <= 0.0001);
+        assert_float_eq!(proj.max, 2.25, abs <= 0.0001);
+
+        let proj = rounded.project(Vec2::new(0.0, 2.0), Transform::new(Vec2::zero(), 0.0, 2.0));
+        assert_float_eq!(proj.max, 3.0, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let rounded = RoundedPolygon::rounded_box(Vec2::new(1.0, 1.0), 0.5).unwrap();
+
+        assert!(rounded.contains_point(Vec2::zero(), Vec2::new(1.4, 0.0)));
+        assert!(rounded.contains_point(Vec2::zero(), Vec2::new(1.3, 1.3)));
+        assert!(!rounded.contains_point(Vec2::zero(), Vec2::new(1.4, 1.4)));
+        assert!(!rounded.contains_point(Vec2::zero(), Vec2::new(0.0, 1.6)));
+    }
+
+    #[test]
+    fn test_support() {
+        let rounded = RoundedPolygon::rounded_box(Vec2::new(1.0, 1.0), 0.5).unwrap();
+
+        let point = rounded.support(Vec2::zero(), Vec2::new(1.0, 1.0));
+        assert_float_eq!(point.x, 1.0 + f64::sqrt(0.125), abs <= 0.0001);
+        assert_float_eq!(point.y, 1.0 + f64::sqrt(0.125), abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_collision() {
+        let rounded = RoundedPolygon::rounded_box(Vec2::new(1.0, 1.0), 0.5).unwrap();
+        let aabb = AABB::new(2.0, 2.0);
+        let circ = Circle::new(0.5);
+
+        // A box resting on top of the flat side
+        let res = rounded.collision_resolution(Vec2::zero(), &aabb, Vec2::new(0.3, 2.4));
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, -1.0, abs <= 0.0001);
+
+        // A circle against a corner arc is pushed out radially
+        let res = circ.collision_resolution(Vec2::new(1.6, 1.6), &rounded, Vec2::zero());
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 1.0 - f64::sqrt(0.72), abs <= 0.0001);
+        assert_float_eq!(res.axis.x, f64::sqrt(0.5), abs <= 0.0001);
+        assert_float_eq!(res.axis.y, f64::sqrt(0.5), abs <= 0.0001);
+
+        assert!(!rounded.collides(Vec2::zero(), &aabb, Vec2::new(2.4, 2.4)));
+        assert!(rounded.collides(
+            Transform::new(Vec2::zero(), FRAC_PI_4, 1.0),
+            &circ,
+            Vec2::new(2.4, 0.0)
+        ));
+    }
+
+    #[test]
+    fn test_raycast() {
+        let rounded = RoundedPolygon::rounded_box(Vec2::new(1.0, 1.0), 0.5).unwrap();
+
+        let hit = rounded
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(-5.0, 0.5),
+                Vec2::new(1.0, 0.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 3.5, abs <= 0.0001);
+        assert_float_eq!(hit.normal.x, -1.0, abs <= 0.0001);
+
+        let hit = rounded
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(5.0, 5.0),
+                Vec2::new(-1.0, -1.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 4.0 - f64::sqrt(0.125), abs <= 0.0001);
+        assert_float_eq!(hit.normal.x, f64::sqrt(0.5), abs <= 0.0001);
+
+        assert!(rounded
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(-5.0, 1.6),
+                Vec2::new(1.0, 0.0),
+                10.0
+            )
+            .is_none());
+    }
+}
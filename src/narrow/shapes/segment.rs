@@ -0,0 +1,451 @@
+use crate::{
+    narrow::{
+        epa::epa_collision_resolution,
+        gjk::Supportable,
+        ray::{raycast_local, RayHit, Raycastable},
+        rounded::{closest_between_segments, rounded_resolution, RoundedFeature},
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+        shapes::{check_finite, ellipse::Ellipse, ShapeError, ShapeType, Shapeable},
+    },
+    transform::Transform,
+    utility::{closest_vertex, project_onto, support_vertex, Projection},
+    vec2::Vec2,
+    NumTolerance,
+};
+
+// A line segment with no thickness. A one-sided segment faces left of its direction, so a polyline drawn left to
+// right is solid from above. Ghost vertices are the neighbouring vertices of a chain, used to keep shapes from
+// catching on the joints between segments
+pub struct Segment<T: NumTolerance> {
+    start: Vec2<T>,
+    end: Vec2<T>,
+    one_sided: bool,
+    previous: Option<Vec2<T>>,
+    next: Option<Vec2<T>>,
+}
+
+impl<T: NumTolerance> Segment<T> {
+    pub fn new(start: Vec2<T>, end: Vec2<T>) -> Self {
+        Self {
+            start,
+            end,
+            one_sided: false,
+            previous: None,
+            next: None,
+        }
+    }
+
+    pub fn one_sided(start: Vec2<T>, end: Vec2<T>) -> Self {
+        Self {
+            one_sided: true,
+            ..Self::new(start, end)
+        }
+    }
+
+    pub fn try_new(start: Vec2<T>, end: Vec2<T>) -> Result<Self, ShapeError> {
+        check_finite(&[start, end])?;
+
+        if (end - start).length_squared().is_zero() {
+            return Err(ShapeError::DegenerateGeometry);
+        }
+
+        Ok(Self::new(start, end))
+    }
+
+    pub fn with_ghosts(self, previous: Option<Vec2<T>>, next: Option<Vec2<T>>) -> Self {
+        Self {
+            previous,
+            next,
+            ..self
+        }
+    }
+
+    pub fn start(&self) -> Vec2<T> {
+        self.start
+    }
+
+    pub fn end(&self) -> Vec2<T> {
+        self.end
+    }
+
+    pub fn is_one_sided(&self) -> bool {
+        self.one_sided
+    }
+
+    pub fn previous(&self) -> Option<Vec2<T>> {
+        self.previous
+    }
+
+    pub fn next(&self) -> Option<Vec2<T>> {
+        self.next
+    }
+
+    // The unit normal on the solid side of a one-sided segment
+    pub fn normal(&self) -> Vec2<T> {
+        (self.end - self.start).rotate_counter_90().normalized()
+    }
+
+    // The ellipse has no vertices to clip against, so the overlap comes from its support mapping instead
+    pub fn ellipse_resolution(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &Ellipse<T>,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T> {
+        let (transform, shape_transform) = (transform.into(), shape_transform.into());
+
+        self.ghosted_resolution(
+            transform,
+            epa_collision_resolution(self, transform, shape, shape_transform),
+            shape_transform.translation,
+            |side| shape.project(side, shape_transform).min,
+        )
+    }
+
+    pub(crate) fn feature_resolution(
+        &self,
+        transform: Transform<T>,
+        feature: &RoundedFeature<T>,
+    ) -> Resolution<T> {
+        let (start, end) = (transform.apply(self.start), transform.apply(self.end));
+        let resolution = rounded_resolution(
+            &[start, end],
+            T::zero(),
+            &feature.vertices,
+            feature.radius,
+            true,
+        );
+
+        let centroid = feature
+            .vertices
+            .iter()
+            .fold(Vec2::zero(), |sum, vertex| sum + *vertex)
+            .scale(T::one() / T::from(feature.vertices.len()).unwrap());
+        let lowest = |side: Vec2<T>| {
+            feature
+                .vertices
+                .iter()
+                .map(|vertex| vertex.dot(side))
+                .fold(T::max_value(), T::min)
+                - feature.radius
+        };
+
+        self.ghosted_resolution(transform, resolution, centroid, lowest)
+    }
+
+    // Drops shapes centred behind a one-sided segment, and replaces any push that only exists because of an internal
+    // joint with a push along the segment's own normal. `lowest` is the other shape's projection minimum on an axis
+    fn ghosted_resolution(
+        &self,
+        transform: Transform<T>,
+        resolution: Resolution<T>,
+        centroid: Vec2<T>,
+        lowest: impl Fn(Vec2<T>) -> T,
+    ) -> Resolution<T> {
+        if !resolution.colliding {
+            return resolution;
+        }
+
+        let (start, end) = (transform.apply(self.start), transform.apply(self.end));
+        let normal = (end - start).rotate_counter_90().normalized();
+
+        if self.one_sided && (centroid - start).dot(normal).is_sign_negative() {
+            return Resolution::new();
+        }
+
+        let push = -resolution.axis;
+        let side = match self.one_sided || !push.dot(normal).is_sign_negative() {
+            true => normal,
+            false => -normal,
+        };
+
+        let along = push.dot((end - start).normalized());
+        let corner = match along {
+            _ if along.is_trivial_abs() => None,
+            _ if along.is_sign_negative() => Some((start, self.previous)),
+            _ => Some((end, self.next)),
+        };
+
+        let admissible = !push.dot(side).is_sign_negative()
+            && match corner {
+                Some((vertex, Some(ghost))) => {
+                    within_corner(vertex, transform.apply(ghost), side, push)
+                }
+                _ => true,
+            };
+        if admissible {
+            return resolution;
+        }
+
+        let depth = start.dot(side) - lowest(side);
+        if depth.is_sign_negative() && !depth.is_trivial_abs() {
+            return Resolution::new();
+        }
+
+        Resolution {
+            colliding: true,
+            penetration: depth,
+            axis: -side,
+        }
+    }
+
+    fn vertices(&self) -> [Vec2<T>; 2] {
+        [self.start, self.end]
+    }
+}
+
+// Pushes out of a joint are only real at a convex corner, and only between the normals of the two edges meeting there
+fn within_corner<T: NumTolerance>(
+    vertex: Vec2<T>,
+    ghost: Vec2<T>,
+    side: Vec2<T>,
+    push: Vec2<T>,
+) -> bool {
+    let offset = ghost - vertex;
+    if !offset.dot(side).is_sign_negative() || offset.dot(side).is_trivial_abs() {
+        return false;
+    }
+
+    let neighbour = offset.rotate_counter_90().normalized();
+    let neighbour = match neighbour.dot(side).is_sign_negative() {
+        true => -neighbour,
+        false => neighbour,
+    };
+
+    let turn = neighbour.cross(side);
+    !(neighbour.cross(push) * turn).is_sign_negative()
+        && !(push.cross(side) * turn).is_sign_negative()
+}
+
+impl<T: NumTolerance> SATable<T> for Segment<T> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        let transform = transform.into();
+        let direction = transform.rotate(self.end - self.start);
+
+        [
+            Axis::Static {
+                vector: direction.rotate_counter_90(),
+                normalized: false,
+            },
+            Axis::Static {
+                vector: direction,
+                normalized: false,
+            },
+            Axis::Dynamic {
+                point: transform.apply(self.start),
+            },
+            Axis::Dynamic {
+                point: transform.apply(self.end),
+            },
+        ]
+        .into_iter()
+    }
+
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        project_onto(transform, &self.vertices(), axis)
+    }
+
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        closest_vertex(point, transform, &self.vertices()) - point
+    }
+
+    fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
+        let transform = transform.into();
+        let (closest, _) = closest_between_segments(
+            (transform.apply(self.start), transform.apply(self.end)),
+            (point, point),
+        );
+
+        (closest - point).length_squared().is_trivial_abs()
+    }
+
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
+    }
+
+    fn collision_resolution<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T>
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Supportable<T> for Segment<T> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T> {
+        support_vertex(transform, &self.vertices(), direction)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for Segment<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        raycast_local(
+            transform,
+            origin,
+            direction,
+            max_toi,
+            |origin, direction, max_toi| {
+                let edge = self.end - self.start;
+                let denom = direction.cross(edge);
+                if denom.is_zero() {
+                    return None;
+                }
+
+                let offset = self.start - origin;
+                let toi = offset.cross(edge) / denom;
+                let along = offset.cross(direction) / denom;
+                if toi.is_sign_negative() || toi > max_toi || along < T::zero() || along > T::one()
+                {
+                    return None;
+                }
+
+                // Rays from behind a one-sided segment pass through it
+                let normal = edge.rotate_counter_90().normalized();
+                match direction.dot(normal).is_sign_negative() {
+                    true => Some((toi, normal)),
+                    false if !self.one_sided => Some((toi, -normal)),
+                    false => None,
+                }
+            },
+        )
+    }
+}
+
+impl<T: NumTolerance> Shapeable<T> for Segment<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
+        ShapeType::Segment(self)
+    }
+}
+
+#[cfg(test)]
+mod segment_tests {
+
+    use float_eq::assert_float_eq;
+
+    use super::{Segment, ShapeError};
+    use crate::{
+        narrow::{
+            ray::Raycastable,
+            sat::SATable,
+            shapes::{aabb::AABB, circle::Circle},
+        },
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_contains_point() {
+        let seg = Segment::new(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 1.0));
+
+        assert!(seg.contains_point(Vec2::zero(), Vec2::new(0.0, 0.5)));
+        assert!(seg.contains_point(Vec2::new(1.0, 0.0), Vec2::new(2.0, 1.0)));
+        assert!(!seg.contains_point(Vec2::zero(), Vec2::new(0.0, 0.6)));
+        assert!(!seg.contains_point(Vec2::zero(), Vec2::new(3.0, 2.0)));
+    }
+
+    #[test]
+    fn test_two_sided() {
+        let seg = Segment::new(Vec2::new(-2.0, 0.0), Vec2::new(2.0, 0.0));
+        let circ = Circle::new(0.5);
+
+        let res = seg.collision_resolution(Vec2::zero(), &circ, Vec2::new(0.0, 0.4));
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, -1.0, abs <= 0.0001);
+
+        let res = circ.collision_resolution(Vec2::new(0.0, -0.4), &seg, Vec2::zero());
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, -1.0, abs <= 0.0001);
+
+        assert!(!seg.collides(Vec2::zero(), &circ, Vec2::new(2.5, 0.3)));
+    }
+
+    #[test]
+    fn test_one_sided() {
+        let seg = Segment::one_sided(Vec2::new(-2.0, 0.0), Vec2::new(2.0, 0.0));
+        let aabb = AABB::new(1.0, 1.0);
+
+        let res = aabb.collision_resolution(Vec2::new(0.0, 0.4), &seg, Vec2::zero());
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, 1.0, abs <= 0.0001);
+
+        // Jumping up through the segment from below
+        assert!(!aabb.collides(Vec2::new(0.0, -0.4), &seg, Vec2::zero()));
+    }
+
+    #[test]
+    fn test_raycast() {
+        let seg = Segment::new(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0));
+        let one_sided = Segment::one_sided(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0));
+
+        let hit = seg
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(0.5, -2.0),
+                Vec2::new(0.0, 1.0),
+                10.0,
+            )
+            .unwrap();
+        assert_float_eq!(hit.toi, 2.0, abs <= 0.0001);
+        assert_float_eq!(hit.normal.y, -1.0, abs <= 0.0001);
+
+        assert!(one_sided
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(0.5, -2.0),
+                Vec2::new(0.0, 1.0),
+                10.0
+            )
+            .is_none());
+        assert!(one_sided
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(0.5, 2.0),
+                Vec2::new(0.0, -1.0),
+                10.0
+            )
+            .is_some());
+        assert!(seg
+            .raycast(
+                Vec2::zero(),
+                Vec2::new(1.5, -2.0),
+                Vec2::new(0.0, 1.0),
+                10.0
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(Segment::try_new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0)).is_ok());
+        assert!(Segment::try_new(Vec2::new(0.0, 0.0), Vec2::new(0.005, 0.0)).is_ok());
+        assert!(matches!(
+            Segment::try_new(Vec2::new(1.0, 0.0), Vec2::new(1.0, 0.0)),
+            Err(ShapeError::DegenerateGeometry)
+        ));
+        assert!(matches!(
+            Segment::try_new(Vec2::new(f64::INFINITY, 0.0), Vec2::new(1.0, 0.0)),
+            Err(ShapeError::NonFinite)
+        ));
+    }
+}
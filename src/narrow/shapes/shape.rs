@@ -0,0 +1,267 @@
+use crate::{
+    broad::bounding_box::BoundingBox,
+    narrow::{
+        ray::{RayHit, Raycastable},
+        sat::{Axis, Resolution, SATable},
+    },
+    transform::Transform,
+    utility::Projection,
+    vec2::Vec2,
+    NumTolerance,
+};
+
+use super::{
+    aabb::AABB, capsule::Capsule, chain::Chain, circle::Circle, compound::Compound,
+    concave::ConcavePolygon, ellipse::Ellipse, half_plane::HalfPlane, obb::OBB, p_gram::Pgram,
+    polygon::Polygon, rounded_polygon::RoundedPolygon, segment::Segment, triangle::Triangle,
+    ShapeType, Shapeable,
+};
+
+// Owns any one of the shapes, so differently shaped colliders can share a container without generics
+pub enum Shape<T: NumTolerance> {
+    AABB(AABB<T>),
+    Capsule(Capsule<T>),
+    Chain(Chain<T>),
+    Circle(Circle<T>),
+    Compound(Compound<T>),
+    Concave(ConcavePolygon<T>),
+    Ellipse(Ellipse<T>),
+    HalfPlane(HalfPlane<T>),
+    OBB(OBB<T>),
+    Pgram(Pgram<T>),
+    Polygon(Polygon<T>),
+    RoundedPolygon(RoundedPolygon<T>),
+    Segment(Segment<T>),
+    Triangle(Triangle<T>),
+}
+
+// Runs the same expression against whichever shape is held
+macro_rules! with_shape {
+    ($held:expr, $shape:ident => $body:expr) => {
+        match $held {
+            Shape::AABB($shape) => $body,
+            Shape::Capsule($shape) => $body,
+            Shape::Chain($shape) => $body,
+            Shape::Circle($shape) => $body,
+            Shape::Compound($shape) => $body,
+            Shape::Concave($shape) => $body,
+            Shape::Ellipse($shape) => $body,
+            Shape::HalfPlane($shape) => $body,
+            Shape::OBB($shape) => $body,
+            Shape::Pgram($shape) => $body,
+            Shape::Polygon($shape) => $body,
+            Shape::RoundedPolygon($shape) => $body,
+            Shape::Segment($shape) => $body,
+            Shape::Triangle($shape) => $body,
+        }
+    };
+}
+
+macro_rules! shape_from {
+    ($($variant:ident => $shape:ty),*) => {
+        $(impl<T: NumTolerance> From<$shape> for Shape<T> {
+            fn from(shape: $shape) -> Self {
+                Shape::$variant(shape)
+            }
+        })*
+    };
+}
+
+shape_from!(
+    AABB => AABB<T>,
+    Capsule => Capsule<T>,
+    Chain => Chain<T>,
+    Circle => Circle<T>,
+    Compound => Compound<T>,
+    Concave => ConcavePolygon<T>,
+    Ellipse => Ellipse<T>,
+    HalfPlane => HalfPlane<T>,
+    OBB => OBB<T>,
+    Pgram => Pgram<T>,
+    Polygon => Polygon<T>,
+    RoundedPolygon => RoundedPolygon<T>,
+    Segment => Segment<T>,
+    Triangle => Triangle<T>
+);
+
+impl<T: NumTolerance> SATable<T> for Shape<T> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        with_shape!(self, shape => shape.axes(transform).collect::<Vec<_>>()).into_iter()
+    }
+
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        with_shape!(self, shape => shape.project(axis, transform))
+    }
+
+    fn bounding_box(&self, transform: impl Into<Transform<T>>) -> BoundingBox<T> {
+        with_shape!(self, shape => shape.bounding_box(transform))
+    }
+
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        with_shape!(self, shape => shape.axis_from_point(transform, point))
+    }
+
+    fn convex_pieces(&self) -> Option<&[Polygon<T>]> {
+        with_shape!(self, shape => shape.convex_pieces())
+    }
+
+    fn contains_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> bool {
+        with_shape!(self, shape => shape.contains_point(transform, point))
+    }
+
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        with_shape!(self, inner => inner.collides(transform, shape, shape_transform))
+    }
+
+    fn collision_resolution<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T>
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        with_shape!(self, inner => inner.collision_resolution(transform, shape, shape_transform))
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for Shape<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        with_shape!(self, shape => shape.raycast(transform, origin, direction, max_toi))
+    }
+}
+
+impl<T: NumTolerance> Shapeable<T> for Shape<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
+        with_shape!(self, shape => shape.shape())
+    }
+}
+
+#[cfg(test)]
+mod shape_tests {
+
+    use float_eq::assert_float_eq;
+
+    use super::Shape;
+    use crate::{
+        broad::aabb_tree::AABBTree,
+        narrow::{
+            dyn_shape::DynShape,
+            sat::SATable,
+            shapes::{
+                aabb::AABB, capsule::Capsule, circle::Circle, compound::Compound,
+                half_plane::HalfPlane, obb::OBB, polygon::Polygon,
+            },
+        },
+        vec2::Vec2,
+    };
+
+    fn world() -> Vec<(Shape<f64>, Vec2<f64>)> {
+        let mut dumbbell = Compound::new(Vec::new());
+        dumbbell.push(Vec2::new(-1.0, 0.0), Circle::new(0.5));
+        dumbbell.push(Vec2::new(1.0, 0.0), Circle::new(0.5));
+
+        vec![
+            (AABB::new(2.0, 2.0).into(), Vec2::new(0.0, 0.0)),
+            (Circle::new(1.0).into(), Vec2::new(1.5, 0.5)),
+            (
+                OBB::new(Vec2::new(1.0, 0.5), 0.3).into(),
+                Vec2::new(-1.5, 0.0),
+            ),
+            (
+                Capsule::new(Vec2::new(0.0, 1.0), 0.5).into(),
+                Vec2::new(0.0, 2.2),
+            ),
+            (dumbbell.into(), Vec2::new(8.0, 0.0)),
+            (
+                Polygon::new(vec![
+                    Vec2::new(0.0, 0.0),
+                    Vec2::new(1.0, 0.0),
+                    Vec2::new(0.0, 1.0),
+                ])
+                .unwrap()
+                .into(),
+                Vec2::new(9.2, -0.2),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_world() {
+        let world = world();
+        let colliding = [(0, 1), (0, 2), (0, 3), (4, 5)];
+
+        for (i, (shape, position)) in world.iter().enumerate() {
+            for (j, (other, other_position)) in world.iter().enumerate().skip(i + 1) {
+                let expected = colliding.contains(&(i, j));
+
+                assert_eq!(shape.collides(*position, other, *other_position), expected);
+                assert_eq!(other.collides(*other_position, shape, *position), expected);
+
+                let res = shape.collision_resolution(*position, other, *other_position);
+                let flipped = other.collision_resolution(*other_position, shape, *position);
+                assert_eq!(res.colliding, expected);
+                assert_eq!(flipped.colliding, expected);
+                if expected {
+                    assert_float_eq!(res.penetration, flipped.penetration, abs <= 0.0001);
+                    assert_float_eq!(res.axis.x, -flipped.axis.x, abs <= 0.0001);
+                    assert_float_eq!(res.axis.y, -flipped.axis.y, abs <= 0.0001);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_concrete() {
+        let (aabb, circle) = (AABB::new(2.0, 2.0), Circle::new(1.0));
+        let (shape, other): (Shape<f64>, Shape<f64>) =
+            (AABB::new(2.0, 2.0).into(), Circle::new(1.0).into());
+
+        let expected = aabb.collision_resolution(Vec2::zero(), &circle, Vec2::new(1.5, 0.5));
+        let res = shape.collision_resolution(Vec2::zero(), &other, Vec2::new(1.5, 0.5));
+
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, expected.penetration, abs <= 0.0001);
+        assert_float_eq!(res.axis.x, expected.axis.x, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, expected.axis.y, abs <= 0.0001);
+        assert!(shape.contains_point(Vec2::zero(), Vec2::new(0.9, -0.9)));
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let floor: Shape<f64> = HalfPlane::new(Vec2::new(0.0, 1.0), 0.0).into();
+        let mut walled = Compound::new(Vec::new());
+        walled.push(Vec2::zero(), Circle::new(1.0));
+        walled.push(Vec2::zero(), HalfPlane::new(Vec2::new(1.0, 0.0), 0.0));
+        let boxed: Box<dyn DynShape<f64>> = Box::new(HalfPlane::new(Vec2::new(0.0, 1.0), 0.0));
+
+        let bounds = floor.bounding_box(Vec2::zero());
+        assert_eq!(bounds.min.x, f64::NEG_INFINITY);
+        assert_float_eq!(bounds.max.y, 0.0, abs <= 0.0001);
+        assert_eq!(walled.bounding_box(Vec2::zero()).min.y, f64::NEG_INFINITY);
+        assert_float_eq!(walled.bounding_box(Vec2::zero()).max.x, 1.0, abs <= 0.0001);
+        assert_eq!(boxed.bounding_box(Vec2::zero()).max.x, f64::INFINITY);
+
+        let mut tree = AABBTree::new(0.1);
+        let wall = tree.insert(floor.bounding_box(Vec2::zero()));
+        let below = tree.insert(AABB::new(1.0, 1.0).bounding_box(Vec2::new(0.0, -3.0)));
+        tree.insert(AABB::new(1.0, 1.0).bounding_box(Vec2::new(0.0, 3.0)));
+
+        assert_eq!(tree.pairs(), vec![(wall, below)]);
+    }
+}
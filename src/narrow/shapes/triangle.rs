@@ -1,9 +1,12 @@
 use crate::{
     narrow::{
-        sat::{Axis, SATable},
-        shapes::{ShapeType, Shapeable},
+        gjk::Supportable,
+        ray::{raycast_local, raycast_vertices, RayHit, Raycastable},
+        sat::{dispatch_collides, dispatch_collision_resolution, Axis, Resolution, SATable},
+        shapes::{check_finite, polygon::is_straight, ShapeError, ShapeType, Shapeable},
     },
-    utility::{closest_vertex, project_onto, Projection},
+    transform::Transform,
+    utility::{closest_vertex, project_onto, support_vertex, Projection},
     vec2::Vec2,
     NumTolerance,
 };
@@ -23,41 +26,103 @@ impl<T: NumTolerance> Triangle<T> {
         }
     }
 
+    pub fn try_new(vertices: &[Vec2<T>; 3]) -> Result<Self, ShapeError> {
+        check_finite(vertices)?;
+
+        if is_straight(vertices[1] - vertices[0], vertices[2] - vertices[0]) {
+            return Err(ShapeError::DegenerateGeometry);
+        }
+
+        Ok(Self::new(vertices))
+    }
+
     pub fn vertices(&self) -> [Vec2<T>; 3] {
         [self.first, self.second, self.third]
     }
 }
 
 impl<T: NumTolerance> SATable<T> for Triangle<T> {
-    fn axes(&self) -> impl Iterator<Item = Axis<T>> {
+    fn axes(&self, transform: impl Into<Transform<T>>) -> impl Iterator<Item = Axis<T>> {
+        let transform = transform.into();
+
         [
             Axis::Static {
-                vector: (self.second - self.first).rotate_counter_90(),
+                vector: transform.rotate((self.second - self.first).rotate_counter_90()),
                 normalized: false,
             },
             Axis::Static {
-                vector: (self.third - self.second).rotate_counter_90(),
+                vector: transform.rotate((self.third - self.second).rotate_counter_90()),
                 normalized: false,
             },
             Axis::Static {
-                vector: (self.first - self.third).rotate_counter_90(),
+                vector: transform.rotate((self.first - self.third).rotate_counter_90()),
                 normalized: false,
             },
         ]
         .into_iter()
     }
 
-    fn project(&self, axis: Vec2<T>, position: Vec2<T>) -> Projection<T> {
-        project_onto(position, &self.vertices(), axis)
+    fn project(&self, axis: Vec2<T>, transform: impl Into<Transform<T>>) -> Projection<T> {
+        project_onto(transform, &self.vertices(), axis)
+    }
+
+    fn axis_from_point(&self, transform: impl Into<Transform<T>>, point: Vec2<T>) -> Vec2<T> {
+        closest_vertex(point, transform, &self.vertices()) - point
     }
 
-    fn axis_from_point(&self, position: Vec2<T>, point: Vec2<T>) -> Vec2<T> {
-        closest_vertex(point, position, &self.vertices()) - point
+    fn collides<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> bool
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collides(self, self.shape(), transform, shape, shape_transform)
+    }
+
+    fn collision_resolution<S>(
+        &self,
+        transform: impl Into<Transform<T>>,
+        shape: &S,
+        shape_transform: impl Into<Transform<T>>,
+    ) -> Resolution<T>
+    where
+        S: SATable<T> + Shapeable<T>,
+    {
+        dispatch_collision_resolution(self, self.shape(), transform, shape, shape_transform)
+    }
+}
+
+impl<T: NumTolerance> Supportable<T> for Triangle<T> {
+    fn support(&self, transform: impl Into<Transform<T>>, direction: Vec2<T>) -> Vec2<T> {
+        support_vertex(transform, &self.vertices(), direction)
+    }
+}
+
+impl<T: NumTolerance> Raycastable<T> for Triangle<T> {
+    fn raycast(
+        &self,
+        transform: impl Into<Transform<T>>,
+        origin: Vec2<T>,
+        direction: Vec2<T>,
+        max_toi: T,
+    ) -> Option<RayHit<T>> {
+        raycast_local(
+            transform,
+            origin,
+            direction,
+            max_toi,
+            |origin, direction, max_toi| {
+                raycast_vertices(&self.vertices(), origin, direction, max_toi)
+            },
+        )
     }
 }
 
 impl<T: NumTolerance> Shapeable<T> for Triangle<T> {
-    fn shape(&self) -> ShapeType<T> {
+    fn shape(&self) -> ShapeType<'_, T> {
         ShapeType::Triangle(self)
     }
 }
@@ -65,7 +130,7 @@ impl<T: NumTolerance> Shapeable<T> for Triangle<T> {
 #[cfg(test)]
 mod triangle_tests {
 
-    use super::Triangle;
+    use super::{ShapeError, Triangle};
     use crate::{
         narrow::{sat::SATable, shapes::contains_perpendicular},
         vec2::Vec2,
@@ -84,17 +149,29 @@ mod triangle_tests {
             Vec2::new(0.0, 1.0 / f64::sqrt(3.0)),
         ]);
 
-        assert!(contains_perpendicular(tri0.axes(), Vec2::new(1.0, 0.0)));
-        assert!(contains_perpendicular(tri0.axes(), Vec2::new(0.0, 1.0)));
-        assert!(contains_perpendicular(tri0.axes(), Vec2::new(1.0, -1.0)));
+        assert!(contains_perpendicular(
+            tri0.axes(Vec2::zero()),
+            Vec2::new(1.0, 0.0)
+        ));
+        assert!(contains_perpendicular(
+            tri0.axes(Vec2::zero()),
+            Vec2::new(0.0, 1.0)
+        ));
+        assert!(contains_perpendicular(
+            tri0.axes(Vec2::zero()),
+            Vec2::new(1.0, -1.0)
+        ));
 
-        assert!(contains_perpendicular(tri1.axes(), Vec2::new(1.0, 0.0)));
         assert!(contains_perpendicular(
-            tri1.axes(),
+            tri1.axes(Vec2::zero()),
+            Vec2::new(1.0, 0.0)
+        ));
+        assert!(contains_perpendicular(
+            tri1.axes(Vec2::zero()),
             Vec2::new(0.5, f64::sqrt(3.0) * 0.5)
         ));
         assert!(contains_perpendicular(
-            tri1.axes(),
+            tri1.axes(Vec2::zero()),
             Vec2::new(-0.5, f64::sqrt(3.0) * 0.5)
         ));
     }
@@ -122,4 +199,36 @@ mod triangle_tests {
         assert!(!tri1.contains_point(Vec2::new(0.0, 1.0), Vec2::new(0.5, 1.0)));
         assert!(!tri1.contains_point(Vec2::new(-10.0, -10.0), Vec2::new(0.0, 0.0)));
     }
+
+    #[test]
+    fn test_try_new() {
+        assert!(Triangle::try_new(&[
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0),
+        ])
+        .is_ok());
+        assert!(Triangle::try_new(&[
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.001, 0.0),
+            Vec2::new(0.0, 0.001),
+        ])
+        .is_ok());
+        assert!(matches!(
+            Triangle::try_new(&[
+                Vec2::new(0.0, 0.0),
+                Vec2::new(1.0, 1.0),
+                Vec2::new(2.0, 2.0),
+            ]),
+            Err(ShapeError::DegenerateGeometry)
+        ));
+        assert!(matches!(
+            Triangle::try_new(&[
+                Vec2::new(0.0, 0.0),
+                Vec2::new(1.0, f64::INFINITY),
+                Vec2::new(0.0, 1.0),
+            ]),
+            Err(ShapeError::NonFinite)
+        ));
+    }
 }
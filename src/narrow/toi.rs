@@ -0,0 +1,232 @@
+use crate::{
+    narrow::{distance::distance, gjk::Supportable},
+    transform::Transform,
+    vec2::Vec2,
+    NumTolerance,
+};
+
+static TOI_MAX_ITERATIONS: usize = 64;
+
+// `normal` points from the first shape towards the second, and the witness points are taken at `toi`
+pub struct TOIResult<T: NumTolerance> {
+    pub toi: T,
+    pub normal: Vec2<T>,
+    pub point_a: Vec2<T>,
+    pub point_b: Vec2<T>,
+}
+
+// Conservative advancement: the shapes can always safely close the current gap at their relative speed along the normal
+#[allow(clippy::too_many_arguments)]
+pub fn shape_cast<T: NumTolerance>(
+    shape: &(impl Supportable<T> + ?Sized),
+    transform: impl Into<Transform<T>>,
+    velocity: Vec2<T>,
+    other: &(impl Supportable<T> + ?Sized),
+    other_transform: impl Into<Transform<T>>,
+    other_velocity: Vec2<T>,
+    max_t: T,
+) -> Option<TOIResult<T>> {
+    let (transform, other_transform) = (transform.into(), other_transform.into());
+
+    // The nearest piece need not be the one moving closer, so each piece is cast on its own
+    let pieces = match (shape.support_pieces(), other.support_pieces()) {
+        (Some(pieces), _) => Some(
+            pieces
+                .iter()
+                .filter_map(|piece| {
+                    shape_cast(
+                        piece,
+                        transform,
+                        velocity,
+                        other,
+                        other_transform,
+                        other_velocity,
+                        max_t,
+                    )
+                })
+                .collect::<Vec<_>>(),
+        ),
+        (None, Some(pieces)) => Some(
+            pieces
+                .iter()
+                .filter_map(|piece| {
+                    shape_cast(
+                        shape,
+                        transform,
+                        velocity,
+                        piece,
+                        other_transform,
+                        other_velocity,
+                        max_t,
+                    )
+                })
+                .collect(),
+        ),
+        (None, None) => None,
+    };
+    if let Some(results) = pieces {
+        return results
+            .into_iter()
+            .min_by(|result, other| result.toi.partial_cmp(&other.toi).unwrap());
+    }
+
+    let relative = velocity - other_velocity;
+    let tolerance = T::zero().error_abs();
+    let mut t = T::zero();
+
+    for _ in 0..TOI_MAX_ITERATIONS {
+        let moved = translated(transform, velocity, t);
+        let other_moved = translated(other_transform, other_velocity, t);
+        let result = distance(shape, moved, other, other_moved);
+
+        if result.distance < tolerance {
+            return Some(TOIResult {
+                toi: t,
+                normal: result.normal,
+                point_a: result.point_a,
+                point_b: result.point_b,
+            });
+        }
+
+        let closing = relative.dot(result.normal);
+        if closing <= T::zero() {
+            return None;
+        }
+
+        // Stopping just short of contact keeps the normal well defined
+        t = t + (result.distance - tolerance / (T::one() + T::one())) / closing;
+        if t > max_t {
+            return None;
+        }
+    }
+
+    None
+}
+
+fn translated<T: NumTolerance>(transform: Transform<T>, velocity: Vec2<T>, t: T) -> Transform<T> {
+    let mut moved = transform;
+    moved.translation = moved.translation + velocity.scale(t);
+
+    moved
+}
+
+#[cfg(test)]
+mod toi_tests {
+
+    use float_eq::assert_float_eq;
+    use std::f64::consts::FRAC_PI_4;
+
+    use super::shape_cast;
+    use crate::{
+        narrow::shapes::{aabb::AABB, circle::Circle},
+        transform::Transform,
+        vec2::Vec2,
+    };
+
+    #[test]
+    fn test_thin_wall() {
+        let bullet = Circle::new(0.5);
+        let wall = AABB::new(0.1, 4.0);
+
+        let result = shape_cast(
+            &bullet,
+            Vec2::new(-10.0, 0.0),
+            Vec2::new(100.0, 0.0),
+            &wall,
+            Vec2::zero(),
+            Vec2::zero(),
+            1.0,
+        )
+        .unwrap();
+        assert_float_eq!(result.toi, 0.0945, abs <= 0.0001);
+        assert_float_eq!(result.normal.x, 1.0, abs <= 0.0001);
+        assert_float_eq!(result.point_a.x, -0.05, abs <= 0.001);
+        assert_float_eq!(result.point_b.x, -0.05, abs <= 0.001);
+
+        assert!(shape_cast(
+            &bullet,
+            Vec2::new(-10.0, 0.0),
+            Vec2::new(100.0, 0.0),
+            &wall,
+            Vec2::zero(),
+            Vec2::zero(),
+            0.05,
+        )
+        .is_none());
+        assert!(shape_cast(
+            &bullet,
+            Vec2::new(-10.0, 3.0),
+            Vec2::new(100.0, 0.0),
+            &wall,
+            Vec2::zero(),
+            Vec2::zero(),
+            1.0,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_both_moving() {
+        let aabb = AABB::new(2.0, 2.0);
+
+        let result = shape_cast(
+            &aabb,
+            Vec2::new(-5.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            &aabb,
+            Vec2::new(5.0, 0.5),
+            Vec2::new(-1.0, 0.0),
+            10.0,
+        )
+        .unwrap();
+        assert_float_eq!(result.toi, 4.0, abs <= 0.001);
+        assert_float_eq!(result.normal.x, 1.0, abs <= 0.0001);
+
+        assert!(shape_cast(
+            &aabb,
+            Vec2::new(-5.0, 0.0),
+            Vec2::new(-1.0, 0.0),
+            &aabb,
+            Vec2::new(5.0, 0.5),
+            Vec2::new(1.0, 0.0),
+            10.0,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_rotated() {
+        let aabb = AABB::new(2.0, 2.0);
+        let circ = Circle::new(1.0);
+
+        let result = shape_cast(
+            &aabb,
+            Transform::new(Vec2::zero(), FRAC_PI_4, 1.0),
+            Vec2::zero(),
+            &circ,
+            Vec2::new(0.0, 10.0),
+            Vec2::new(0.0, -2.0),
+            10.0,
+        )
+        .unwrap();
+        assert_float_eq!(result.toi, (9.0 - f64::sqrt(2.0)) / 2.0, abs <= 0.001);
+        assert_float_eq!(result.point_a.y, f64::sqrt(2.0), abs <= 0.001);
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let circ = Circle::new(1.0);
+
+        let result = shape_cast(
+            &circ,
+            Vec2::zero(),
+            Vec2::new(1.0, 0.0),
+            &circ,
+            Vec2::new(1.0, 0.0),
+            Vec2::zero(),
+            1.0,
+        )
+        .unwrap();
+        assert_float_eq!(result.toi, 0.0, abs <= 0.0001);
+    }
+}
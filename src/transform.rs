@@ -0,0 +1,178 @@
+use crate::{
+    narrow::shapes::{check_finite, check_size, ShapeError},
+    vec2::Vec2,
+    NumTolerance,
+};
+
+#[derive(Clone, Copy)]
+pub struct Transform<T: NumTolerance> {
+    pub translation: Vec2<T>,
+    rotation: Vec2<T>,
+    scale: T,
+}
+
+impl<T: NumTolerance> Transform<T> {
+    // The scale must be positive and finite, since inverse_apply divides by it
+    pub fn new(translation: Vec2<T>, angle: T, scale: T) -> Self {
+        debug_assert!(
+            scale.is_finite() && scale > T::zero(),
+            "transform scale must be positive and finite"
+        );
+
+        Self {
+            translation,
+            rotation: Vec2::new(angle.cos(), angle.sin()),
+            scale,
+        }
+    }
+
+    pub fn try_new(translation: Vec2<T>, angle: T, scale: T) -> Result<Self, ShapeError> {
+        check_finite(&[translation, Vec2::new(angle, T::zero())])?;
+        check_size(scale)?;
+
+        Ok(Self::new(translation, angle, scale))
+    }
+
+    pub fn identity() -> Self {
+        Self::from_translation(Vec2::zero())
+    }
+
+    pub fn from_translation(translation: Vec2<T>) -> Self {
+        Self {
+            translation,
+            rotation: Vec2::new(T::one(), T::zero()),
+            scale: T::one(),
+        }
+    }
+
+    pub fn angle(&self) -> T {
+        self.rotation.y.atan2(self.rotation.x)
+    }
+
+    pub fn scale(&self) -> T {
+        self.scale
+    }
+
+    pub fn is_translation(&self) -> bool {
+        self.rotation.y.is_trivial_abs()
+            && self.rotation.x.is_sign_positive()
+            && (self.scale - T::one()).is_trivial_abs()
+    }
+
+    pub fn rotate(&self, vector: Vec2<T>) -> Vec2<T> {
+        Vec2::new(
+            self.rotation.x * vector.x - self.rotation.y * vector.y,
+            self.rotation.y * vector.x + self.rotation.x * vector.y,
+        )
+    }
+
+    pub fn inverse_rotate(&self, vector: Vec2<T>) -> Vec2<T> {
+        Vec2::new(
+            self.rotation.x * vector.x + self.rotation.y * vector.y,
+            self.rotation.x * vector.y - self.rotation.y * vector.x,
+        )
+    }
+
+    pub fn apply_vector(&self, vector: Vec2<T>) -> Vec2<T> {
+        self.rotate(vector).scale(self.scale)
+    }
+
+    pub fn apply(&self, point: Vec2<T>) -> Vec2<T> {
+        self.translation + self.apply_vector(point)
+    }
+
+    pub fn inverse_apply(&self, point: Vec2<T>) -> Vec2<T> {
+        self.inverse_rotate(point - self.translation)
+            .scale(T::one() / self.scale)
+    }
+
+    // Places a transform given relative to this one into the same space as this one
+    pub fn then(&self, local: Transform<T>) -> Self {
+        Self {
+            translation: self.apply(local.translation),
+            rotation: self.rotate(local.rotation),
+            scale: self.scale * local.scale,
+        }
+    }
+}
+
+impl<T: NumTolerance> From<Vec2<T>> for Transform<T> {
+    fn from(translation: Vec2<T>) -> Self {
+        Self::from_translation(translation)
+    }
+}
+
+#[cfg(test)]
+mod test_transform {
+
+    use float_eq::assert_float_eq;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use super::Transform;
+    use crate::{narrow::shapes::ShapeError, vec2::Vec2};
+
+    #[test]
+    fn test_apply() {
+        let transform = Transform::new(Vec2::new(1.0, 2.0), FRAC_PI_2, 2.0);
+
+        let point = transform.apply(Vec2::new(1.0, 0.0));
+        assert_float_eq!(point.x, 1.0, abs <= 0.0001);
+        assert_float_eq!(point.y, 4.0, abs <= 0.0001);
+
+        let vector = transform.apply_vector(Vec2::new(0.0, -1.5));
+        assert_float_eq!(vector.x, 3.0, abs <= 0.0001);
+        assert_float_eq!(vector.y, 0.0, abs <= 0.0001);
+
+        let local = transform.inverse_apply(point);
+        assert_float_eq!(local.x, 1.0, abs <= 0.0001);
+        assert_float_eq!(local.y, 0.0, abs <= 0.0001);
+
+        let rotated = transform.inverse_rotate(transform.rotate(Vec2::new(-3.0, 0.5)));
+        assert_float_eq!(rotated.x, -3.0, abs <= 0.0001);
+        assert_float_eq!(rotated.y, 0.5, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_then() {
+        let parent = Transform::new(Vec2::new(1.0, 0.0), FRAC_PI_4, 2.0);
+        let child = Transform::new(Vec2::new(1.0, 1.0), FRAC_PI_4, 0.5);
+        let combined = parent.then(child);
+
+        assert_float_eq!(combined.angle(), FRAC_PI_2, abs <= 0.0001);
+        assert_float_eq!(combined.scale(), 1.0, abs <= 0.0001);
+
+        let point = Vec2::new(0.5, -2.0);
+        let expected = parent.apply(child.apply(point));
+        assert_float_eq!(combined.apply(point).x, expected.x, abs <= 0.0001);
+        assert_float_eq!(combined.apply(point).y, expected.y, abs <= 0.0001);
+    }
+
+    #[test]
+    fn test_is_translation() {
+        assert!(Transform::from(Vec2::new(3.0, -1.0)).is_translation());
+        assert!(Transform::new(Vec2::new(3.0, -1.0), 0.0, 1.0).is_translation());
+        assert!(!Transform::new(Vec2::zero(), FRAC_PI_4, 1.0).is_translation());
+        assert!(!Transform::new(Vec2::zero(), 0.0, 1.5).is_translation());
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(Transform::try_new(Vec2::new(3.0, -1.0), FRAC_PI_4, 2.0).is_ok());
+        assert!(matches!(
+            Transform::try_new(Vec2::zero(), 0.0, 0.0),
+            Err(ShapeError::ZeroSize)
+        ));
+        assert!(matches!(
+            Transform::try_new(Vec2::zero(), 0.0, -1.0),
+            Err(ShapeError::NegativeSize)
+        ));
+        assert!(matches!(
+            Transform::try_new(Vec2::zero(), f64::NAN, 1.0),
+            Err(ShapeError::NonFinite)
+        ));
+        assert!(matches!(
+            Transform::try_new(Vec2::zero(), 0.0, f64::INFINITY),
+            Err(ShapeError::NonFinite)
+        ));
+    }
+}
@@ -1,5 +1,6 @@
-use crate::{vec2::Vec2, NumTolerance};
+use crate::{transform::Transform, vec2::Vec2, NumTolerance};
 
+#[derive(Clone, Copy)]
 pub struct Projection<T: NumTolerance> {
     pub min: T,
     pub max: T,
@@ -12,17 +13,32 @@ impl<T: NumTolerance> Projection<T> {
             max: T::min_value(),
         }
     }
+
+    pub fn overlaps(&self, rhs: &Projection<T>) -> bool {
+        self.min < rhs.max && rhs.min < self.max
+    }
+}
+
+impl<T: NumTolerance> Default for Projection<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 pub fn project_onto<T: NumTolerance>(
-    position: Vec2<T>,
+    transform: impl Into<Transform<T>>,
     vertices: &[Vec2<T>],
     axis: Vec2<T>,
 ) -> Projection<T> {
+    let transform = transform.into();
     let mut projection = Projection::new();
 
+    // Projecting the local vertices onto the axis brought into local space saves transforming every vertex
+    let offset = axis.dot(transform.translation);
+    let local_axis = transform.inverse_rotate(axis).scale(transform.scale());
+
     for vertex in vertices.iter() {
-        let proj = axis.dot(position + *vertex);
+        let proj = offset + local_axis.dot(*vertex);
 
         projection.min = T::min(projection.min, proj);
         projection.max = T::max(projection.max, proj);
@@ -33,17 +49,19 @@ pub fn project_onto<T: NumTolerance>(
 
 pub fn closest_vertex<T: NumTolerance>(
     point: Vec2<T>,
-    position: Vec2<T>,
+    transform: impl Into<Transform<T>>,
     vertices: &[Vec2<T>],
 ) -> Vec2<T> {
+    let transform = transform.into();
     let mut closest = Vec2::zero();
     let mut min = T::max_value();
 
     for vertex in vertices.iter() {
-        let d_s = ((position + *vertex) - point).length_squared();
+        let world = transform.apply(*vertex);
+        let d_s = (world - point).length_squared();
 
         if d_s < min {
-            closest = *vertex + position;
+            closest = world;
             min = d_s;
         }
     }
@@ -51,12 +69,35 @@ pub fn closest_vertex<T: NumTolerance>(
     closest
 }
 
+pub fn support_vertex<T: NumTolerance>(
+    transform: impl Into<Transform<T>>,
+    vertices: &[Vec2<T>],
+    direction: Vec2<T>,
+) -> Vec2<T> {
+    let transform = transform.into();
+    let local_direction = transform.inverse_rotate(direction);
+
+    let mut support = Vec2::zero();
+    let mut max = T::min_value();
+
+    for vertex in vertices.iter() {
+        let proj = local_direction.dot(*vertex);
+
+        if proj > max {
+            support = *vertex;
+            max = proj;
+        }
+    }
+
+    transform.apply(support)
+}
+
 #[cfg(test)]
 mod test_utility {
 
     use float_eq::assert_float_eq;
 
-    use super::{closest_vertex, project_onto};
+    use super::{closest_vertex, project_onto, support_vertex};
     use crate::vec2::Vec2;
 
     #[test]
@@ -113,4 +154,27 @@ mod test_utility {
             abs <= 0.0001
         );
     }
+
+    #[test]
+    fn test_support() {
+        let vertices = vec![
+            Vec2::zero(),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(-2.0, -3.0),
+            Vec2::new(1.5, -2.5),
+            Vec2::new(10.0, 0.0),
+        ];
+
+        let support = support_vertex(Vec2::new(1.0, 1.0), &vertices, Vec2::new(1.0, 0.0));
+        assert_float_eq!(support.x, 11.0, abs <= 0.0001);
+        assert_float_eq!(support.y, 1.0, abs <= 0.0001);
+
+        let support = support_vertex(Vec2::zero(), &vertices, Vec2::new(-1.0, -0.5));
+        assert_float_eq!(support.x, -2.0, abs <= 0.0001);
+        assert_float_eq!(support.y, -3.0, abs <= 0.0001);
+
+        let support = support_vertex(Vec2::new(0.0, -1.0), &vertices, Vec2::new(0.0, 1.0));
+        assert_float_eq!(support.x, 2.0, abs <= 0.0001);
+        assert_float_eq!(support.y, 0.0, abs <= 0.0001);
+    }
 }
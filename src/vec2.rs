@@ -22,7 +22,7 @@ impl<T: NumTolerance> Vec2<T> {
     }
 
     pub fn length_squared(&self) -> T {
-        return (self.x * self.x) + (self.y * self.y);
+        (self.x * self.x) + (self.y * self.y)
     }
 
     pub fn length(&self) -> T {
@@ -38,6 +38,10 @@ impl<T: NumTolerance> Vec2<T> {
         self.x * rhs.x + self.y * rhs.y
     }
 
+    pub fn cross(&self, rhs: Vec2<T>) -> T {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
     pub fn rotate_counter_90(&self) -> Self {
         Self::new(-self.y, self.x)
     }
@@ -108,6 +112,8 @@ mod test_vectors {
         assert_float_eq!((a - c).y, 11.7, abs <= 0.0001);
 
         assert_float_eq!(a.dot(b), -30.0, abs <= 0.0001);
+        assert_float_eq!(a.cross(b), 410.0, abs <= 0.0001);
+        assert_float_eq!(b.cross(a), -410.0, abs <= 0.0001);
 
         assert_float_eq!(a.scale(0.1).x, 3.0, abs <= 0.0001);
         assert_float_eq!(a.scale(0.1).y, 1.0, abs <= 0.0001);
@@ -136,7 +136,7 @@ fn test_aabb_polygon_collision() {
         Vec2::new(0.0, -1.0),
     ])
     .unwrap();
-    let poly1 = Polygon::new(vec![
+    let poly1 = Polygon::new_without_collinear(vec![
         Vec2::new(-0.5, 0.0),
         Vec2::new(1.5, 1.0),
         Vec2::new(0.5, 0.0),
@@ -0,0 +1,132 @@
+use collideoscope::{
+    narrow::{
+        ray::Raycastable,
+        sat::SATable,
+        shapes::{
+            aabb::AABB, capsule::Capsule, circle::Circle, compound::Compound,
+            concave::ConcavePolygon, shape::Shape,
+        },
+    },
+    transform::Transform,
+    vec2::Vec2,
+};
+use float_eq::assert_float_eq;
+use std::f64::consts::FRAC_PI_2;
+
+// A table: a wide top resting on two legs
+fn table() -> Compound<f64> {
+    Compound::new(vec![
+        (Vec2::new(0.0, 1.0).into(), AABB::new(4.0, 0.2).into()),
+        (Vec2::new(-1.8, 0.0).into(), AABB::new(0.2, 2.0).into()),
+        (Vec2::new(1.8, 0.0).into(), AABB::new(0.2, 2.0).into()),
+    ])
+}
+
+#[test]
+fn test_compound_aabb_collision() {
+    let table = table();
+    let aabb = AABB::new(1.0, 1.0);
+
+    // Sits under the top, between the legs
+    assert!(!table.collides(Vec2::zero(), &aabb, Vec2::new(0.0, -0.2)));
+    assert!(!aabb.collides(Vec2::new(0.0, -0.2), &table, Vec2::zero()));
+    assert!(
+        !aabb
+            .collision_resolution(Vec2::new(0.0, -0.2), &table, Vec2::zero())
+            .colliding
+    );
+
+    assert!(table.collides(Vec2::zero(), &aabb, Vec2::new(0.0, 0.5)));
+    assert!(aabb.collides(Vec2::new(0.0, 0.5), &table, Vec2::zero()));
+
+    let res = aabb.collision_resolution(Vec2::new(0.0, 0.5), &table, Vec2::zero());
+    assert!(res.colliding);
+    assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+    assert_float_eq!(res.axis.normalized().y, -1.0, abs <= 0.0001);
+
+    let res = table.collision_resolution(Vec2::zero(), &aabb, Vec2::new(0.0, 0.5));
+    assert!(res.colliding);
+    assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+    assert_float_eq!(res.axis.normalized().y, 1.0, abs <= 0.0001);
+}
+
+#[test]
+fn test_compound_transformed() {
+    let table = table();
+    let circ = Circle::new(0.5);
+
+    // Turned on its side, the top faces the negative x axis
+    let turned = Transform::new(Vec2::new(5.0, 0.0), FRAC_PI_2, 1.0);
+    assert!(circ.collides(Vec2::new(3.6, 0.0), &table, turned));
+    assert!(table.collides(turned, &circ, Vec2::new(3.6, 0.0)));
+    assert!(!circ.collides(Vec2::new(5.0, 0.0), &table, turned));
+
+    let doubled = Transform::new(Vec2::zero(), 0.0, 2.0);
+    assert!(circ.collides(Vec2::new(0.0, 2.6), &table, doubled));
+    assert!(!circ.collides(Vec2::new(0.0, 2.8), &table, doubled));
+}
+
+#[test]
+fn test_compound_compound_collision() {
+    let table = table();
+    let mut cart = Compound::new(Vec::new());
+    cart.push(Vec2::zero(), AABB::new(1.0, 0.5));
+    cart.push(Vec2::new(0.0, 0.5), Capsule::new(Vec2::new(0.5, 0.0), 0.1));
+
+    assert!(!table.collides(Vec2::zero(), &cart, Vec2::new(0.0, 0.0)));
+    assert!(table.collides(Vec2::zero(), &cart, Vec2::new(0.0, 0.4)));
+    assert!(cart.collides(Vec2::new(0.0, 0.4), &table, Vec2::zero()));
+}
+
+#[test]
+fn test_compound_concave_child() {
+    let wall = ConcavePolygon::new(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ])
+    .unwrap();
+    let compound = Compound::new(vec![(Vec2::new(10.0, 0.0).into(), Shape::Concave(wall))]);
+    let aabb = AABB::new(0.6, 0.6);
+
+    assert!(!aabb.collides(Vec2::new(11.6, 1.6), &compound, Vec2::zero()));
+    assert!(aabb.collides(Vec2::new(11.5, 1.2), &compound, Vec2::zero()));
+}
+
+#[test]
+fn test_compound_raycast() {
+    let table = table();
+
+    let hit = table
+        .raycast(
+            Vec2::zero(),
+            Vec2::new(1.8, 5.0),
+            Vec2::new(0.0, -1.0),
+            10.0,
+        )
+        .unwrap();
+    assert_float_eq!(hit.toi, 3.9, abs <= 0.0001);
+    assert_float_eq!(hit.normal.y, 1.0, abs <= 0.0001);
+
+    let hit = table
+        .raycast(
+            Vec2::zero(),
+            Vec2::new(0.0, -5.0),
+            Vec2::new(0.0, 1.0),
+            10.0,
+        )
+        .unwrap();
+    assert_float_eq!(hit.toi, 5.9, abs <= 0.0001);
+
+    assert!(table
+        .raycast(
+            Vec2::zero(),
+            Vec2::new(0.0, -5.0),
+            Vec2::new(1.0, 0.0),
+            10.0
+        )
+        .is_none());
+}
@@ -0,0 +1,155 @@
+use collideoscope::{
+    narrow::{
+        distance::distance,
+        epa::epa_collision_resolution,
+        gjk::gjk_collides,
+        manifold::contact_manifold,
+        ray::Raycastable,
+        sat::SATable,
+        shapes::{aabb::AABB, circle::Circle, concave::ConcavePolygon, polygon::Polygon},
+        toi::shape_cast,
+    },
+    vec2::Vec2,
+};
+use float_eq::assert_float_eq;
+
+fn l_shape() -> ConcavePolygon<f64> {
+    ConcavePolygon::new(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn test_concave_aabb_collision() {
+    let wall = l_shape();
+    let aabb = AABB::new(0.6, 0.6);
+
+    // Sits in the notch, inside the convex hull but clear of the wall
+    assert!(!wall.collides(Vec2::zero(), &aabb, Vec2::new(1.6, 1.6)));
+    assert!(!aabb.collides(Vec2::new(1.6, 1.6), &wall, Vec2::zero()));
+    assert!(
+        !aabb
+            .collision_resolution(Vec2::new(1.6, 1.6), &wall, Vec2::zero())
+            .colliding
+    );
+
+    assert!(wall.collides(Vec2::zero(), &aabb, Vec2::new(1.5, 1.2)));
+    assert!(aabb.collides(Vec2::new(1.5, 1.2), &wall, Vec2::zero()));
+
+    let res = aabb.collision_resolution(Vec2::new(1.5, 1.2), &wall, Vec2::zero());
+    assert!(res.colliding);
+    assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+    assert_float_eq!(res.axis.normalized().y, 1.0, abs <= 0.0001);
+
+    let res = wall.collision_resolution(Vec2::zero(), &aabb, Vec2::new(1.5, 1.2));
+    assert!(res.colliding);
+    assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+    assert_float_eq!(res.axis.normalized().y, -1.0, abs <= 0.0001);
+}
+
+#[test]
+fn test_concave_circle_collision() {
+    let wall = l_shape();
+    let circ = Circle::new(0.5);
+
+    assert!(circ.collides(Vec2::new(2.4, 0.5), &wall, Vec2::zero()));
+    assert!(wall.collides(Vec2::zero(), &circ, Vec2::new(2.4, 0.5)));
+    assert!(!circ.collides(Vec2::new(1.8, 1.8), &wall, Vec2::zero()));
+    assert!(!wall.collides(Vec2::zero(), &circ, Vec2::new(1.8, 1.8)));
+}
+
+#[test]
+fn test_concave_concave_collision() {
+    let wall = l_shape();
+
+    assert!(wall.collides(Vec2::zero(), &wall, Vec2::new(1.5, 0.5)));
+    assert!(!wall.collides(Vec2::zero(), &wall, Vec2::new(1.1, 1.1)));
+}
+
+#[test]
+fn test_concave_polygon_collision() {
+    let wall = l_shape();
+    let poly = Polygon::new(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(0.5, 0.0),
+        Vec2::new(0.5, 0.5),
+        Vec2::new(0.0, 0.5),
+    ])
+    .unwrap();
+
+    assert!(poly.collides(Vec2::new(0.8, 1.8), &wall, Vec2::zero()));
+    assert!(!poly.collides(Vec2::new(1.2, 1.2), &wall, Vec2::zero()));
+}
+
+#[test]
+fn test_concave_raycast() {
+    let wall = l_shape();
+
+    let hit = wall
+        .raycast(
+            Vec2::zero(),
+            Vec2::new(1.5, 3.0),
+            Vec2::new(0.0, -1.0),
+            10.0,
+        )
+        .unwrap();
+    assert_float_eq!(hit.toi, 2.0, abs <= 0.0001);
+    assert_float_eq!(hit.normal.y, 1.0, abs <= 0.0001);
+
+    assert!(wall
+        .raycast(Vec2::zero(), Vec2::new(3.0, 1.5), Vec2::new(-1.0, 0.0), 1.5)
+        .is_none());
+}
+
+#[test]
+fn test_concave_direct_queries() {
+    let wall = l_shape();
+    let aabb = AABB::new(0.6, 0.6);
+    let (notch, overlapping) = (Vec2::new(1.6, 1.6), Vec2::new(1.5, 1.2));
+
+    // Every entry point sees the notch, not the convex hull
+    assert!(!wall.sat_collides(Vec2::zero(), &aabb, notch));
+    assert!(!aabb.sat_collides(notch, &wall, Vec2::zero()));
+    assert!(!gjk_collides(&wall, Vec2::zero(), &aabb, notch));
+    assert!(!epa_collision_resolution(&aabb, notch, &wall, Vec2::zero()).colliding);
+    assert!(contact_manifold(&wall, Vec2::zero(), &aabb, notch).is_none());
+    assert_float_eq!(
+        distance(&wall, Vec2::zero(), &aabb, notch).distance,
+        0.3,
+        abs <= 0.0001
+    );
+
+    let res = aabb.sat_collision_resolution(overlapping, &wall, Vec2::zero());
+    assert!(res.colliding);
+    assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+    assert_float_eq!(res.axis.normalized().y, 1.0, abs <= 0.0001);
+
+    let res = epa_collision_resolution(&wall, Vec2::zero(), &aabb, overlapping);
+    assert!(res.colliding);
+    assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+    assert_float_eq!(res.axis.y, -1.0, abs <= 0.0001);
+    assert!(gjk_collides(&aabb, overlapping, &wall, Vec2::zero()));
+
+    let manifold = contact_manifold(&aabb, overlapping, &wall, Vec2::zero()).unwrap();
+    assert_float_eq!(manifold.axis.y, 1.0, abs <= 0.0001);
+    assert_float_eq!(manifold.contacts[0].depth, 0.1, abs <= 0.0001);
+
+    // Dropping into the notch from above only stops on the floor of the notch
+    let hit = shape_cast(
+        &aabb,
+        Vec2::new(1.6, 4.0),
+        Vec2::new(0.0, -4.0),
+        &wall,
+        Vec2::zero(),
+        Vec2::zero(),
+        1.0,
+    )
+    .unwrap();
+    assert_float_eq!(hit.toi, 0.675, abs <= 0.001);
+}
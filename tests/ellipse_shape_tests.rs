@@ -0,0 +1,131 @@
+use collideoscope::{
+    narrow::{
+        epa::epa_collision_resolution,
+        sat::SATable,
+        shapes::{
+            aabb::AABB, capsule::Capsule, chain::Chain, circle::Circle, compound::Compound,
+            concave::ConcavePolygon, ellipse::Ellipse, obb::OBB, p_gram::Pgram, polygon::Polygon,
+            segment::Segment, shape::Shape, triangle::Triangle,
+        },
+    },
+    transform::Transform,
+    vec2::Vec2,
+};
+use float_eq::assert_float_eq;
+
+fn convex_shapes() -> Vec<Shape<f64>> {
+    vec![
+        AABB::new(2.0, 1.0).into(),
+        Capsule::new(Vec2::new(0.5, 0.5), 0.3).into(),
+        Circle::new(0.7).into(),
+        Ellipse::new(0.5, 1.5).into(),
+        OBB::new(Vec2::new(1.0, 0.5), 0.3).into(),
+        Pgram::new(Vec2::new(1.0, 0.0), Vec2::new(0.5, 1.0)).into(),
+        Polygon::new(vec![
+            Vec2::new(-1.0, -0.5),
+            Vec2::new(1.0, -0.5),
+            Vec2::new(0.5, 0.5),
+            Vec2::new(-0.5, 0.5),
+        ])
+        .unwrap()
+        .into(),
+        Segment::new(Vec2::new(-1.0, -0.2), Vec2::new(1.0, 0.2)).into(),
+        Triangle::new(&[
+            Vec2::new(-1.0, -0.5),
+            Vec2::new(1.0, -0.5),
+            Vec2::new(0.0, 0.8),
+        ])
+        .into(),
+    ]
+}
+
+#[test]
+fn test_ellipse_convex_collision() {
+    let ell = Ellipse::new(2.0, 1.0);
+    let placements = [
+        Transform::new(Vec2::new(0.0, 0.9), 0.0, 1.0),
+        Transform::new(Vec2::new(1.8, 0.3), 0.7, 1.0),
+        Transform::new(Vec2::new(-1.5, -0.6), -1.2, 0.8),
+    ];
+
+    for shape in convex_shapes() {
+        for placement in placements {
+            assert!(ell.collides(Vec2::zero(), &shape, placement));
+            assert!(shape.collides(placement, &ell, Vec2::zero()));
+
+            let res = ell.collision_resolution(Vec2::zero(), &shape, placement);
+            let flipped = shape.collision_resolution(placement, &ell, Vec2::zero());
+            assert!(res.colliding && flipped.colliding);
+            assert_float_eq!(res.penetration, flipped.penetration, abs <= 0.0001);
+            assert_float_eq!(res.axis.x, -flipped.axis.x, abs <= 0.0001);
+            assert_float_eq!(res.axis.y, -flipped.axis.y, abs <= 0.0001);
+
+            // Pushing the ellipse out along the resolution separates the pair
+            let moved = res.axis.normalized().scale(res.penetration + 0.01);
+            assert!(!ell.collides(moved, &shape, placement));
+            assert!(ell.collides(moved.scale(0.9), &shape, placement));
+
+            assert!(!ell.collides(Vec2::zero(), &shape, placement.translation.scale(4.0)));
+        }
+    }
+}
+
+#[test]
+fn test_ellipse_ellipse_collision() {
+    let ell = Ellipse::new(2.0, 1.0);
+    let upright = Transform::new(Vec2::new(2.5, 0.0), std::f64::consts::FRAC_PI_2, 1.0);
+
+    let res = ell.collision_resolution(Vec2::zero(), &ell, upright);
+    let epa = epa_collision_resolution(&ell, Vec2::zero(), &ell, upright);
+    assert!(res.colliding);
+    assert_float_eq!(res.penetration, 0.5, abs <= 0.0001);
+    assert_float_eq!(res.penetration, epa.penetration, abs <= 0.0001);
+    assert_float_eq!(res.axis.x, -1.0, abs <= 0.0001);
+
+    assert!(!ell.collides(
+        Vec2::zero(),
+        &ell,
+        Transform::new(Vec2::new(3.1, 0.0), 1.5, 1.0)
+    ));
+}
+
+#[test]
+fn test_ellipse_multi_piece_collision() {
+    let ell = Ellipse::new(2.0, 1.0);
+
+    let mut compound = Compound::new(Vec::new());
+    compound.push(Vec2::new(-1.0, 0.0), Circle::new(0.5));
+    compound.push(Vec2::new(1.0, 0.0), AABB::new(1.0, 1.0));
+    let concave = ConcavePolygon::new(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(2.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ])
+    .unwrap();
+
+    assert!(ell.collides(Vec2::zero(), &compound, Vec2::new(2.3, 1.2)));
+    assert!(!ell.collides(Vec2::zero(), &compound, Vec2::new(4.0, 1.2)));
+    assert!(compound.collides(Vec2::new(-2.5, 1.2), &ell, Vec2::zero()));
+
+    assert!(ell.collides(Vec2::zero(), &concave, Vec2::new(0.5, 0.5)));
+    assert!(!ell.collides(Vec2::zero(), &concave, Vec2::new(1.5, 0.8)));
+
+    // An ellipse landing on one-sided terrain rests on it, but passes up through it from below
+    let ground = Chain::new(
+        vec![
+            Vec2::new(-4.0, 0.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+        ],
+        false,
+        true,
+    );
+    let res = ell.collision_resolution(Vec2::new(0.5, 0.9), &ground, Vec2::zero());
+    assert!(res.colliding);
+    assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+    assert_float_eq!(res.axis.y, 1.0, abs <= 0.0001);
+    assert!(!ell.collides(Vec2::new(0.5, -0.9), &ground, Vec2::zero()));
+}
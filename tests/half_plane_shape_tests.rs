@@ -0,0 +1,166 @@
+use collideoscope::{
+    narrow::{
+        ray::Raycastable,
+        sat::SATable,
+        shapes::{
+            aabb::AABB, capsule::Capsule, chain::Chain, circle::Circle, compound::Compound,
+            concave::ConcavePolygon, ellipse::Ellipse, half_plane::HalfPlane, obb::OBB,
+            p_gram::Pgram, polygon::Polygon, rounded_polygon::RoundedPolygon, segment::Segment,
+            shape::Shape, triangle::Triangle,
+        },
+    },
+    transform::Transform,
+    vec2::Vec2,
+};
+use float_eq::assert_float_eq;
+
+// Each shape with how far it reaches below its own origin
+fn shapes() -> Vec<(Shape<f64>, f64)> {
+    let mut dumbbell = Compound::new(Vec::new());
+    dumbbell.push(Vec2::new(-1.0, 0.0), Circle::new(0.5));
+    dumbbell.push(Vec2::new(1.0, -0.5), AABB::new(1.0, 1.0));
+
+    vec![
+        (AABB::new(2.0, 1.0).into(), 0.5),
+        (Capsule::new(Vec2::new(0.0, 0.5), 0.3).into(), 0.8),
+        (
+            Chain::new(
+                vec![
+                    Vec2::new(-2.0, 0.0),
+                    Vec2::new(0.0, -0.4),
+                    Vec2::new(2.0, 0.0),
+                ],
+                false,
+                true,
+            )
+            .into(),
+            0.4,
+        ),
+        (Circle::new(0.7).into(), 0.7),
+        (dumbbell.into(), 1.0),
+        (
+            ConcavePolygon::new(vec![
+                Vec2::new(0.0, -1.0),
+                Vec2::new(2.0, -1.0),
+                Vec2::new(2.0, 0.0),
+                Vec2::new(1.0, 0.0),
+                Vec2::new(1.0, 1.0),
+                Vec2::new(0.0, 1.0),
+            ])
+            .unwrap()
+            .into(),
+            1.0,
+        ),
+        (Ellipse::new(0.5, 1.5).into(), 1.5),
+        (HalfPlane::new(Vec2::new(0.0, -1.0), 0.6).into(), 0.6),
+        (OBB::new(Vec2::new(1.0, 0.5), 0.0).into(), 0.5),
+        (
+            Pgram::new(Vec2::new(1.0, 0.0), Vec2::new(0.5, 1.0)).into(),
+            0.5,
+        ),
+        (
+            Polygon::new(vec![
+                Vec2::new(-1.0, -0.5),
+                Vec2::new(1.0, -0.5),
+                Vec2::new(0.0, 0.5),
+            ])
+            .unwrap()
+            .into(),
+            0.5,
+        ),
+        (
+            RoundedPolygon::rounded_box(Vec2::new(1.0, 0.5), 0.25)
+                .unwrap()
+                .into(),
+            0.75,
+        ),
+        (
+            Segment::new(Vec2::new(-1.0, -0.2), Vec2::new(1.0, 0.2)).into(),
+            0.2,
+        ),
+        (
+            Triangle::new(&[
+                Vec2::new(-1.0, -0.5),
+                Vec2::new(1.0, -0.5),
+                Vec2::new(0.0, 0.8),
+            ])
+            .into(),
+            0.5,
+        ),
+    ]
+}
+
+#[test]
+fn test_half_plane_every_shape() {
+    let floor = HalfPlane::new(Vec2::new(0.0, 1.0), 0.0);
+
+    for (shape, reach) in shapes() {
+        let position = Vec2::new(3.0, reach - 0.1);
+
+        let res = floor.collision_resolution(Vec2::zero(), &shape, position);
+        let flipped = shape.collision_resolution(position, &floor, Vec2::zero());
+        assert!(res.colliding && flipped.colliding);
+        assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+        assert_float_eq!(flipped.penetration, 0.1, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, -1.0, abs <= 0.0001);
+        assert_float_eq!(flipped.axis.y, 1.0, abs <= 0.0001);
+
+        let lifted = Vec2::new(3.0, reach + 0.1);
+        assert!(!floor.collides(Vec2::zero(), &shape, lifted));
+        assert!(!shape.collides(lifted, &floor, Vec2::zero()));
+    }
+}
+
+#[test]
+fn test_half_plane_transformed() {
+    let wall = HalfPlane::new(Vec2::new(1.0, 0.0), 1.0);
+    let circ = Circle::new(0.5);
+
+    // Rotated to face down and scaled, the wall becomes a ceiling at y = 2
+    let ceiling = Transform::new(Vec2::new(0.0, 4.0), -std::f64::consts::FRAC_PI_2, 2.0);
+    let res = circ.collision_resolution(Vec2::new(-7.0, 1.8), &wall, ceiling);
+    assert!(res.colliding);
+    assert_float_eq!(res.penetration, 0.3, abs <= 0.0001);
+    assert_float_eq!(res.axis.y, -1.0, abs <= 0.0001);
+}
+
+#[test]
+fn test_half_plane_far_arena() {
+    let centre = Vec2::new(1.0e7, -3.0e7);
+    let walls = [
+        (Vec2::new(1.0, 0.0), Vec2::new(-1.0, 0.0)),
+        (Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0)),
+        (Vec2::new(0.0, 1.0), Vec2::new(0.0, -1.0)),
+        (Vec2::new(0.0, -1.0), Vec2::new(0.0, 1.0)),
+    ];
+    let circ = Circle::new(0.5);
+
+    // The walls sit 10 units from a centre far away from the origin, and contacts a thousandth deep still resolve
+    for (normal, side) in walls {
+        let wall = HalfPlane::new(normal, -10.0);
+
+        let resting = centre + side.scale(9.501);
+        let res = circ.collision_resolution(resting, &wall, centre);
+        assert!(res.colliding);
+        assert_float_eq!(res.penetration, 0.001, abs <= 0.00001);
+        assert_float_eq!(res.axis.x, normal.x, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, normal.y, abs <= 0.0001);
+
+        assert!(!circ.collides(centre + side.scale(9.499), &wall, centre));
+        assert!(wall.contains_point(centre, centre + side.scale(10.001)));
+        assert!(!wall.contains_point(centre, centre + side.scale(9.999)));
+    }
+}
+
+#[test]
+fn test_half_plane_raycast() {
+    let floor = HalfPlane::new(Vec2::new(0.0, 1.0), 0.0);
+    let far = Vec2::new(5.0e6, 5.0e6);
+
+    let hit = floor
+        .raycast(far, far + Vec2::new(0.0, 3.0), Vec2::new(0.6, -0.8), 10.0)
+        .unwrap();
+    assert_float_eq!(hit.toi, 3.75, abs <= 0.0001);
+    assert_float_eq!(hit.point.y, far.y, abs <= 0.0001);
+    assert_float_eq!(hit.normal.y, 1.0, abs <= 0.0001);
+}
@@ -0,0 +1,209 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use collideoscope::{
+    narrow::shapes::{
+        aabb::AABB, capsule::Capsule, circle::Circle, obb::OBB, p_gram::Pgram, polygon::Polygon,
+        triangle::Triangle,
+    },
+    transform::Transform,
+    vec2::Vec2,
+};
+
+pub mod shape_tests;
+use shape_tests::{test_collides, test_does_not_collide};
+
+#[test]
+fn test_obb_aabb_collision() {
+    let upright = OBB::new(Vec2::new(2.0, 0.5), FRAC_PI_2);
+    let diamond = OBB::new(Vec2::new(1.0, 1.0), FRAC_PI_4);
+
+    let box0 = AABB::new(2.0, 2.0);
+    let box1 = AABB::new(0.4, 0.4);
+
+    test_collides(
+        &upright,
+        Vec2::zero(),
+        &box0,
+        Vec2::new(0.0, 2.9),
+        0.1,
+        Vec2::new(0.0, -1.0),
+    );
+    test_collides(
+        &diamond,
+        Vec2::new(1.0, 1.0),
+        &box0,
+        Transform::new(Vec2::new(1.2, 3.3), FRAC_PI_4, 1.0),
+        2.0 - 2.5 / f64::sqrt(2.0),
+        Vec2::new(-1.0, -1.0),
+    );
+
+    test_does_not_collide(&diamond, Vec2::zero(), &box1, Vec2::new(0.95, 0.95));
+    test_does_not_collide(&upright, Vec2::zero(), &box0, Vec2::new(1.6, 0.0));
+}
+
+#[test]
+fn test_obb_capsule_collision() {
+    let diamond = OBB::new(Vec2::new(0.5, 0.5), FRAC_PI_4);
+    let cap = Capsule::new(Vec2::new(0.0, 1.0), 0.5);
+
+    test_collides(
+        &diamond,
+        Vec2::zero(),
+        &cap,
+        Vec2::new(1.0, 0.0),
+        f64::sqrt(0.5) - 0.5,
+        Vec2::new(-1.0, 0.0),
+    );
+    test_collides(
+        &diamond,
+        Vec2::new(2.0, 2.0),
+        &cap,
+        Vec2::new(2.0, 3.9),
+        0.5 + f64::sqrt(0.5) - 0.9,
+        Vec2::new(0.0, -1.0),
+    );
+
+    test_does_not_collide(&diamond, Vec2::zero(), &cap, Vec2::new(1.3, 0.0));
+    test_does_not_collide(&diamond, Vec2::new(-4.0, 1.0), &cap, Vec2::new(12.0, 9.0));
+}
+
+#[test]
+fn test_obb_circle_collision() {
+    let upright = OBB::new(Vec2::new(1.0, 0.5), FRAC_PI_2);
+    let circ = Circle::new(1.0);
+
+    test_collides(
+        &upright,
+        Vec2::zero(),
+        &circ,
+        Vec2::new(1.3, 0.0),
+        0.2,
+        Vec2::new(-1.0, 0.0),
+    );
+    test_collides(
+        &upright,
+        Vec2::new(-1.0, 2.0),
+        &circ,
+        Vec2::new(-1.0, 0.2),
+        0.2,
+        Vec2::new(0.0, 1.0),
+    );
+
+    test_does_not_collide(&upright, Vec2::zero(), &circ, Vec2::new(1.6, 0.0));
+    test_does_not_collide(&upright, Vec2::zero(), &circ, Vec2::new(1.4, 1.7));
+}
+
+#[test]
+fn test_obb_obb_collision() {
+    let diamond = OBB::new(Vec2::new(1.0, 1.0), FRAC_PI_4);
+    let flat = OBB::new(Vec2::new(1.0, 0.5), 0.0);
+
+    test_collides(
+        &diamond,
+        Vec2::zero(),
+        &flat,
+        Vec2::new(2.3, 0.0),
+        f64::sqrt(2.0) - 1.3,
+        Vec2::new(-1.0, 0.0),
+    );
+    test_collides(
+        &flat,
+        Transform::new(Vec2::zero(), FRAC_PI_4, 1.0),
+        &flat,
+        Vec2::new(0.0, 1.4),
+        0.5 + 1.5 / f64::sqrt(2.0) - 1.4,
+        Vec2::new(0.0, -1.0),
+    );
+
+    test_does_not_collide(&diamond, Vec2::zero(), &flat, Vec2::new(2.5, 0.0));
+    test_does_not_collide(&diamond, Vec2::zero(), &flat, Vec2::new(1.6, 1.6));
+}
+
+#[test]
+fn test_obb_pgram_collision() {
+    let diamond = OBB::new(Vec2::new(1.0, 1.0), FRAC_PI_4);
+    let gram = Pgram::new(Vec2::new(2.0, 0.0), Vec2::new(0.0, 2.0));
+
+    test_collides(
+        &diamond,
+        Vec2::zero(),
+        &gram,
+        Vec2::new(0.0, 2.2),
+        f64::sqrt(2.0) - 1.2,
+        Vec2::new(0.0, -1.0),
+    );
+    test_collides(
+        &diamond,
+        Vec2::new(5.0, 5.0),
+        &gram,
+        Vec2::new(3.8, 5.0),
+        f64::sqrt(2.0) - 0.2,
+        Vec2::new(1.0, 0.0),
+    );
+
+    test_does_not_collide(&diamond, Vec2::zero(), &gram, Vec2::new(0.0, 2.5));
+    test_does_not_collide(&diamond, Vec2::zero(), &gram, Vec2::new(1.85, 1.85));
+}
+
+#[test]
+fn test_obb_polygon_collision() {
+    let flat = OBB::new(Vec2::new(1.0, 0.5), 0.0);
+    let poly = Polygon::new(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(0.5, 2.0),
+        Vec2::new(0.0, 1.0),
+    ])
+    .unwrap();
+
+    test_collides(
+        &flat,
+        Vec2::zero(),
+        &poly,
+        Vec2::new(0.9, -0.4),
+        0.1,
+        Vec2::new(-1.0, 0.0),
+    );
+    test_collides(
+        &flat,
+        Transform::new(Vec2::zero(), FRAC_PI_2, 1.0),
+        &poly,
+        Vec2::new(-0.5, 0.9),
+        0.1,
+        Vec2::new(0.0, -1.0),
+    );
+
+    test_does_not_collide(&flat, Vec2::zero(), &poly, Vec2::new(1.1, -0.4));
+    test_does_not_collide(&flat, Vec2::zero(), &poly, Vec2::new(-0.5, -2.6));
+}
+
+#[test]
+fn test_obb_triangle_collision() {
+    let wide = OBB::new(Vec2::new(0.5, 1.0), FRAC_PI_2);
+    let tri = Triangle::new(&[
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+
+    test_collides(
+        &wide,
+        Vec2::zero(),
+        &tri,
+        Vec2::new(0.8, -0.2),
+        0.2,
+        Vec2::new(-1.0, 0.0),
+    );
+    test_collides(
+        &wide,
+        Vec2::zero(),
+        &tri,
+        Vec2::new(-0.5, 0.4),
+        0.1,
+        Vec2::new(0.0, -1.0),
+    );
+
+    test_does_not_collide(&wide, Vec2::zero(), &tri, Vec2::new(1.1, -0.2));
+    test_does_not_collide(&wide, Vec2::zero(), &tri, Vec2::new(0.3, 0.6));
+}
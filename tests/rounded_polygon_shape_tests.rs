@@ -0,0 +1,144 @@
+use collideoscope::{
+    narrow::{
+        manifold::contact_manifold,
+        shapes::{
+            aabb::AABB, capsule::Capsule, circle::Circle, polygon::Polygon,
+            rounded_polygon::RoundedPolygon, triangle::Triangle,
+        },
+    },
+    transform::Transform,
+    vec2::Vec2,
+};
+use float_eq::assert_float_eq;
+
+pub mod shape_tests;
+use shape_tests::{test_collides, test_does_not_collide};
+
+#[test]
+fn test_rounded_polygon_aabb_collision() {
+    let rounded = RoundedPolygon::rounded_box(Vec2::new(1.0, 0.5), 0.5).unwrap();
+    let aabb = AABB::new(2.0, 2.0);
+
+    test_collides(
+        &rounded,
+        Vec2::zero(),
+        &aabb,
+        Vec2::new(0.5, 1.9),
+        0.1,
+        Vec2::new(0.0, -1.0),
+    );
+    test_collides(
+        &rounded,
+        Vec2::zero(),
+        &aabb,
+        Vec2::new(-2.4, 0.2),
+        0.1,
+        Vec2::new(1.0, 0.0),
+    );
+
+    // The corner of the box meets the arc of the rounded corner
+    test_collides(
+        &rounded,
+        Vec2::zero(),
+        &aabb,
+        Vec2::new(2.3, 1.8),
+        0.5 - f64::sqrt(0.18),
+        Vec2::new(-1.0, -1.0),
+    );
+
+    test_does_not_collide(&rounded, Vec2::zero(), &aabb, Vec2::new(2.4, 1.9));
+    test_does_not_collide(&rounded, Vec2::zero(), &aabb, Vec2::new(0.0, 2.1));
+}
+
+#[test]
+fn test_rounded_polygon_rounded_collision() {
+    let rounded = RoundedPolygon::new(
+        vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(1.0, 1.0),
+        ],
+        0.25,
+    )
+    .unwrap();
+    let circ = Circle::new(0.5);
+    let cap = Capsule::new(Vec2::new(1.0, 0.0), 0.25);
+
+    test_collides(
+        &rounded,
+        Vec2::zero(),
+        &circ,
+        Vec2::new(1.0, -0.6),
+        0.15,
+        Vec2::new(0.0, 1.0),
+    );
+    test_collides(
+        &rounded,
+        Vec2::zero(),
+        &cap,
+        Vec2::new(1.0, 1.4),
+        0.1,
+        Vec2::new(0.0, -1.0),
+    );
+    test_collides(
+        &rounded,
+        Vec2::zero(),
+        &rounded,
+        Vec2::new(0.0, -1.4),
+        0.1,
+        Vec2::new(0.0, 1.0),
+    );
+
+    test_does_not_collide(&rounded, Vec2::zero(), &circ, Vec2::new(-0.6, -0.6));
+    test_does_not_collide(&rounded, Vec2::zero(), &cap, Vec2::new(1.0, 1.6));
+}
+
+#[test]
+fn test_rounded_polygon_polygonal_collision() {
+    let rounded = RoundedPolygon::rounded_box(Vec2::new(0.5, 0.5), 0.5).unwrap();
+    let tri = Triangle::new(&[
+        Vec2::new(-1.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    let poly = Polygon::new(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(0.0, 1.0),
+    ])
+    .unwrap();
+
+    test_collides(
+        &rounded,
+        Transform::new(Vec2::zero(), 0.3, 1.0),
+        &tri,
+        Vec2::new(0.0, 1.0),
+        0.5 * (f64::cos(0.3) + f64::sin(0.3)) - 0.5,
+        Vec2::new(0.0, -1.0),
+    );
+    test_collides(
+        &rounded,
+        Vec2::zero(),
+        &poly,
+        Vec2::new(-1.9, -0.5),
+        0.1,
+        Vec2::new(1.0, 0.0),
+    );
+
+    test_does_not_collide(&rounded, Vec2::zero(), &poly, Vec2::new(1.1, 1.1));
+}
+
+#[test]
+fn test_rounded_polygon_manifold() {
+    let rounded = RoundedPolygon::rounded_box(Vec2::new(1.0, 0.5), 0.5).unwrap();
+    let aabb = AABB::new(1.0, 1.0);
+
+    // A box resting on the flat top touches along the whole of its bottom face
+    let manifold = contact_manifold(&rounded, Vec2::zero(), &aabb, Vec2::new(0.0, 1.4)).unwrap();
+    assert_eq!(manifold.contacts.len(), 2);
+    for contact in manifold.contacts {
+        assert_float_eq!(contact.point.y, 0.9, abs <= 0.0001);
+        assert_float_eq!(contact.depth, 0.1, abs <= 0.0001);
+    }
+}
@@ -0,0 +1,132 @@
+use collideoscope::{
+    narrow::{
+        sat::SATable,
+        shapes::{
+            aabb::AABB, capsule::Capsule, chain::Chain, circle::Circle, polygon::Polygon,
+            segment::Segment,
+        },
+    },
+    transform::Transform,
+    vec2::Vec2,
+};
+use float_eq::assert_float_eq;
+use std::f64::consts::FRAC_PI_2;
+
+pub mod shape_tests;
+use shape_tests::{test_collides, test_does_not_collide};
+
+#[test]
+fn test_segment_collision() {
+    let seg = Segment::new(Vec2::new(-2.0, 0.0), Vec2::new(2.0, 0.0));
+    let aabb = AABB::new(2.0, 2.0);
+    let circ = Circle::new(1.0);
+    let cap = Capsule::new(Vec2::new(0.0, 1.0), 0.5);
+    let tri = Polygon::new(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 1.0),
+    ])
+    .unwrap();
+
+    test_collides(
+        &seg,
+        Vec2::zero(),
+        &aabb,
+        Vec2::new(0.5, 0.9),
+        0.1,
+        Vec2::new(0.0, -1.0),
+    );
+    test_collides(
+        &seg,
+        Vec2::zero(),
+        &circ,
+        Vec2::new(2.3, 0.4),
+        0.5,
+        Vec2::new(-0.6, -0.8),
+    );
+    test_collides(
+        &seg,
+        Transform::new(Vec2::new(1.0, 0.0), FRAC_PI_2, 0.5),
+        &cap,
+        Vec2::new(0.6, 0.0),
+        0.1,
+        Vec2::new(1.0, 0.0),
+    );
+    test_collides(
+        &seg,
+        Vec2::zero(),
+        &tri,
+        Vec2::new(-0.5, -0.9),
+        0.1,
+        Vec2::new(0.0, 1.0),
+    );
+
+    test_does_not_collide(&seg, Vec2::zero(), &aabb, Vec2::new(0.0, 1.1));
+    test_does_not_collide(&seg, Vec2::zero(), &circ, Vec2::new(2.7, 0.8));
+    test_does_not_collide(&seg, Vec2::zero(), &tri, Vec2::new(2.1, 0.0));
+}
+
+#[test]
+fn test_chain_terrain() {
+    // A slope rising into a flat top, solid from above
+    let terrain = Chain::new(
+        vec![
+            Vec2::new(-6.0, 0.0),
+            Vec2::new(-2.0, 0.0),
+            Vec2::new(0.0, 2.0),
+            Vec2::new(4.0, 2.0),
+        ],
+        false,
+        true,
+    );
+    let aabb = AABB::new(1.0, 1.0);
+    let circ = Circle::new(0.5);
+
+    let res = circ.collision_resolution(Vec2::new(-4.0, 0.4), &terrain, Vec2::zero());
+    assert!(res.colliding);
+    assert_float_eq!(res.penetration, 0.1, abs <= 0.0001);
+    assert_float_eq!(res.axis.y, 1.0, abs <= 0.0001);
+
+    // Resting on the slope pushes out along its normal
+    let res = circ.collision_resolution(Vec2::new(-1.1, 1.1), &terrain, Vec2::zero());
+    assert!(res.colliding);
+    assert_float_eq!(res.penetration, 0.5 - f64::sqrt(0.02), abs <= 0.0001);
+    assert_float_eq!(res.axis.x, -f64::sqrt(0.5), abs <= 0.0001);
+    assert_float_eq!(res.axis.y, f64::sqrt(0.5), abs <= 0.0001);
+
+    // Sliding across the top of the slope only ever pushes up
+    for x in [0.3, 0.45] {
+        let res = aabb.collision_resolution(Vec2::new(x, 2.4), &terrain, Vec2::zero());
+        assert!(res.colliding);
+        assert_float_eq!(res.axis.x, 0.0, abs <= 0.0001);
+        assert_float_eq!(res.axis.y, 1.0, abs <= 0.0001);
+    }
+
+    // Jumping up through the flat top from below
+    assert!(!aabb.collides(Vec2::new(2.0, 1.6), &terrain, Vec2::zero()));
+    assert!(!terrain.collides(Vec2::zero(), &circ, Vec2::new(2.0, 1.7)));
+}
+
+#[test]
+fn test_closed_chain() {
+    // A closed counter clockwise loop faces inwards, like the walls of an arena
+    let arena = Chain::new(
+        vec![
+            Vec2::new(-5.0, -5.0),
+            Vec2::new(5.0, -5.0),
+            Vec2::new(5.0, 5.0),
+            Vec2::new(-5.0, 5.0),
+        ],
+        true,
+        true,
+    );
+    let circ = Circle::new(1.0);
+
+    let res = circ.collision_resolution(Vec2::new(4.5, 0.0), &arena, Vec2::zero());
+    assert!(res.colliding);
+    assert_float_eq!(res.penetration, 0.5, abs <= 0.0001);
+    assert_float_eq!(res.axis.x, -1.0, abs <= 0.0001);
+
+    assert!(!circ.collides(Vec2::zero(), &arena, Vec2::zero()));
+    assert!(!circ.collides(Vec2::new(5.5, 0.0), &arena, Vec2::zero()));
+}
@@ -1,56 +1,104 @@
 use collideoscope::{
-    narrow::{sat::SATable, shapes::Shapeable},
+    narrow::{
+        distance::distance,
+        epa::epa_collision_resolution,
+        gjk::{gjk_collides, Supportable},
+        sat::SATable,
+        shapes::Shapeable,
+    },
+    transform::Transform,
     vec2::Vec2,
     NumTolerance,
 };
 
+// EPA only approximates curved boundaries with a polytope, so its normal is compared coarsely
+fn assert_epa_axis<T: NumTolerance>(epa_axis: Vec2<T>, sat_axis: Vec2<T>) {
+    assert!(
+        (epa_axis - sat_axis.normalized())
+            .length_squared()
+            .is_trivial_abs(),
+        "SAT: {}, {} | EPA: {}, {}",
+        sat_axis.x,
+        sat_axis.y,
+        epa_axis.x,
+        epa_axis.y
+    );
+    assert!(
+        epa_axis.dot(sat_axis).is_sign_positive(),
+        "SAT: {}, {} | EPA: {}, {}",
+        sat_axis.x,
+        sat_axis.y,
+        epa_axis.x,
+        epa_axis.y
+    );
+}
+
 pub fn test_collides<T: NumTolerance>(
-    l: &(impl Shapeable<T> + SATable<T>),
-    l_pos: Vec2<T>,
-    r: &(impl Shapeable<T> + SATable<T>),
-    r_pos: Vec2<T>,
+    l: &(impl Shapeable<T> + SATable<T> + Supportable<T>),
+    l_pos: impl Into<Transform<T>>,
+    r: &(impl Shapeable<T> + SATable<T> + Supportable<T>),
+    r_pos: impl Into<Transform<T>>,
     expected_pen: T,
     expected_axis: Vec2<T>,
 ) {
+    let (l_pos, r_pos) = (l_pos.into(), r_pos.into());
+
     assert!(
         l.collides(l_pos, r, r_pos),
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x,
-        l_pos.y,
-        r_pos.x,
-        r_pos.y
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y
     );
     assert!(
         r.collides(r_pos, l, l_pos),
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x,
-        l_pos.y,
-        r_pos.x,
-        r_pos.y
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y
     );
 
     assert!(
         l.sat_collides(l_pos, r, r_pos),
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x,
-        l_pos.y,
-        r_pos.x,
-        r_pos.y
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y
     );
     assert!(
         r.sat_collides(r_pos, l, l_pos),
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x,
-        l_pos.y,
-        r_pos.x,
-        r_pos.y
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y
+    );
+
+    assert!(
+        gjk_collides(l, l_pos, r, r_pos),
+        "Left: {}, {} | Right: {}, {}",
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y
+    );
+    assert!(
+        gjk_collides(r, r_pos, l, l_pos),
+        "Left: {}, {} | Right: {}, {}",
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y
     );
 
     let l_res = l.collision_resolution(l_pos, r, r_pos);
     assert!(
         l_res.colliding,
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x, l_pos.y, r_pos.x, r_pos.y
+        l_pos.translation.x, l_pos.translation.y, r_pos.translation.x, r_pos.translation.y
     );
     assert!(
         l_res.penetration.is_difference_trivial(expected_pen),
@@ -81,7 +129,7 @@ pub fn test_collides<T: NumTolerance>(
     assert!(
         r_res.colliding,
         "Left: {}, {} - Right: {}, {}",
-        l_pos.x, l_pos.y, r_pos.x, r_pos.y
+        l_pos.translation.x, l_pos.translation.y, r_pos.translation.x, r_pos.translation.y
     );
     assert!(
         r_res.penetration.is_difference_trivial(expected_pen),
@@ -112,7 +160,7 @@ pub fn test_collides<T: NumTolerance>(
     assert!(
         l_res_sat.colliding,
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x, l_pos.y, r_pos.x, r_pos.y
+        l_pos.translation.x, l_pos.translation.y, r_pos.translation.x, r_pos.translation.y
     );
     assert!(
         l_res_sat.penetration.is_difference_trivial(expected_pen),
@@ -141,7 +189,7 @@ pub fn test_collides<T: NumTolerance>(
     assert!(
         r_res_sat.colliding,
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x, l_pos.y, r_pos.x, r_pos.y
+        l_pos.translation.x, l_pos.translation.y, r_pos.translation.x, r_pos.translation.y
     );
     assert!(
         r_res_sat.penetration.is_difference_trivial(expected_pen),
@@ -165,73 +213,159 @@ pub fn test_collides<T: NumTolerance>(
         r_res_sat.axis.x,
         r_res_sat.axis.y
     );
+
+    let l_res_epa = epa_collision_resolution(l, l_pos, r, r_pos);
+    assert!(
+        l_res_epa.colliding,
+        "Left: {}, {} | Right: {}, {}",
+        l_pos.translation.x, l_pos.translation.y, r_pos.translation.x, r_pos.translation.y
+    );
+    assert!(
+        l_res_epa
+            .penetration
+            .is_difference_trivial(l_res_sat.penetration),
+        "SAT: {} | EPA: {}",
+        l_res_sat.penetration,
+        l_res_epa.penetration
+    );
+    assert_epa_axis(l_res_epa.axis, l_res_sat.axis);
+
+    let r_res_epa = epa_collision_resolution(r, r_pos, l, l_pos);
+    assert!(
+        r_res_epa.colliding,
+        "Left: {}, {} | Right: {}, {}",
+        l_pos.translation.x, l_pos.translation.y, r_pos.translation.x, r_pos.translation.y
+    );
+    assert!(
+        r_res_epa
+            .penetration
+            .is_difference_trivial(r_res_sat.penetration),
+        "SAT: {} | EPA: {}",
+        r_res_sat.penetration,
+        r_res_epa.penetration
+    );
+    assert_epa_axis(r_res_epa.axis, r_res_sat.axis);
+
+    let l_dist = distance(l, l_pos, r, r_pos);
+    assert!(
+        l_dist
+            .distance
+            .is_difference_trivial(-l_res_sat.penetration),
+        "SAT: {} | Distance: {}",
+        l_res_sat.penetration,
+        l_dist.distance
+    );
 }
 
 pub fn test_does_not_collide<T: NumTolerance>(
-    l: &(impl Shapeable<T> + SATable<T>),
-    l_pos: Vec2<T>,
-    r: &(impl Shapeable<T> + SATable<T>),
-    r_pos: Vec2<T>,
+    l: &(impl Shapeable<T> + SATable<T> + Supportable<T>),
+    l_pos: impl Into<Transform<T>>,
+    r: &(impl Shapeable<T> + SATable<T> + Supportable<T>),
+    r_pos: impl Into<Transform<T>>,
 ) {
+    let (l_pos, r_pos) = (l_pos.into(), r_pos.into());
+
     assert!(
         !l.collides(l_pos, r, r_pos),
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x,
-        l_pos.y,
-        r_pos.x,
-        r_pos.y
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y
     );
     assert!(
         !r.collides(r_pos, l, l_pos),
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x,
-        l_pos.y,
-        r_pos.x,
-        r_pos.y
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y
     );
 
     assert!(
         !l.sat_collides(l_pos, r, r_pos),
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x,
-        l_pos.y,
-        r_pos.x,
-        r_pos.y
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y
     );
     assert!(
         !r.sat_collides(r_pos, l, l_pos),
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x,
-        l_pos.y,
-        r_pos.x,
-        r_pos.y
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y
+    );
+
+    assert!(
+        !gjk_collides(l, l_pos, r, r_pos),
+        "Left: {}, {} | Right: {}, {}",
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y
+    );
+    assert!(
+        !gjk_collides(r, r_pos, l, l_pos),
+        "Left: {}, {} | Right: {}, {}",
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y
     );
 
     let l_res = l.collision_resolution(l_pos, r, r_pos);
     assert!(
         !l_res.colliding,
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x, l_pos.y, r_pos.x, r_pos.y
+        l_pos.translation.x, l_pos.translation.y, r_pos.translation.x, r_pos.translation.y
     );
 
     let r_res = r.collision_resolution(r_pos, l, l_pos);
     assert!(
         !r_res.colliding,
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x, l_pos.y, r_pos.x, r_pos.y
+        l_pos.translation.x, l_pos.translation.y, r_pos.translation.x, r_pos.translation.y
     );
 
     let l_res_sat = l.sat_collision_resolution(l_pos, r, r_pos);
     assert!(
         !l_res_sat.colliding,
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x, l_pos.y, r_pos.x, r_pos.y
+        l_pos.translation.x, l_pos.translation.y, r_pos.translation.x, r_pos.translation.y
     );
 
     let r_res_sat = r.sat_collision_resolution(r_pos, l, l_pos);
     assert!(
         !r_res_sat.colliding,
         "Left: {}, {} | Right: {}, {}",
-        l_pos.x, l_pos.y, r_pos.x, r_pos.y
+        l_pos.translation.x, l_pos.translation.y, r_pos.translation.x, r_pos.translation.y
+    );
+
+    let l_res_epa = epa_collision_resolution(l, l_pos, r, r_pos);
+    assert!(
+        !l_res_epa.colliding,
+        "Left: {}, {} | Right: {}, {}",
+        l_pos.translation.x, l_pos.translation.y, r_pos.translation.x, r_pos.translation.y
+    );
+
+    let r_res_epa = epa_collision_resolution(r, r_pos, l, l_pos);
+    assert!(
+        !r_res_epa.colliding,
+        "Left: {}, {} | Right: {}, {}",
+        l_pos.translation.x, l_pos.translation.y, r_pos.translation.x, r_pos.translation.y
+    );
+
+    let l_dist = distance(l, l_pos, r, r_pos);
+    assert!(
+        !l_dist.distance.is_sign_negative(),
+        "Left: {}, {} | Right: {}, {} | Distance: {}",
+        l_pos.translation.x,
+        l_pos.translation.y,
+        r_pos.translation.x,
+        r_pos.translation.y,
+        l_dist.distance
     );
 }
@@ -0,0 +1,142 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use collideoscope::{
+    narrow::{
+        sat::SATable,
+        shapes::{aabb::AABB, capsule::Capsule, circle::Circle, p_gram::Pgram, triangle::Triangle},
+    },
+    transform::Transform,
+    vec2::Vec2,
+};
+use float_eq::assert_float_eq;
+
+pub mod shape_tests;
+use shape_tests::{test_collides, test_does_not_collide};
+
+#[test]
+fn test_rotated_aabb_collision() {
+    let box0 = AABB::new(2.0, 2.0);
+    let box1 = AABB::new(0.4, 0.4);
+    let diamond = Transform::new(Vec2::zero(), FRAC_PI_4, 1.0);
+
+    test_collides(
+        &box0,
+        diamond,
+        &box0,
+        Vec2::new(2.2, 0.0),
+        f64::sqrt(2.0) - 1.2,
+        Vec2::new(-1.0, 0.0),
+    );
+
+    test_does_not_collide(&box0, diamond, &box1, Vec2::new(0.95, 0.95));
+    test_does_not_collide(&box0, diamond, &box0, Vec2::new(2.5, 0.0));
+}
+
+#[test]
+fn test_rotated_triangle_collision() {
+    let tri = Triangle::new(&[
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    let aabb = AABB::new(1.0, 1.0);
+
+    test_collides(
+        &tri,
+        Transform::new(Vec2::zero(), FRAC_PI_2, 1.0),
+        &aabb,
+        Vec2::new(-0.5, 1.4),
+        0.1 / f64::sqrt(2.0),
+        Vec2::new(1.0, -1.0),
+    );
+
+    test_does_not_collide(&tri, Vec2::zero(), &aabb, Vec2::new(-0.5, 1.4));
+}
+
+#[test]
+fn test_scaled_circle_collision() {
+    let circ = Circle::new(1.0);
+
+    test_collides(
+        &circ,
+        Transform::new(Vec2::zero(), 0.0, 2.0),
+        &circ,
+        Vec2::new(2.5, 0.0),
+        0.5,
+        Vec2::new(-1.0, 0.0),
+    );
+
+    test_does_not_collide(
+        &circ,
+        Transform::new(Vec2::zero(), 0.0, 2.0),
+        &circ,
+        Vec2::new(3.1, 0.0),
+    );
+}
+
+#[test]
+fn test_rotated_capsule_collision() {
+    let cap = Capsule::new(Vec2::new(1.0, 0.0), 0.5);
+    let aabb = AABB::new(2.0, 2.0);
+    let upright = Transform::new(Vec2::zero(), FRAC_PI_2, 1.0);
+
+    test_collides(
+        &cap,
+        upright,
+        &aabb,
+        Vec2::new(1.3, 0.0),
+        0.2,
+        Vec2::new(-1.0, 0.0),
+    );
+
+    test_does_not_collide(&cap, upright, &aabb, Vec2::new(1.6, 0.0));
+}
+
+#[test]
+fn test_rotated_pgram_collision() {
+    let gram = Pgram::new(Vec2::new(2.0, 0.0), Vec2::new(0.0, 2.0));
+    let circ = Circle::new(0.5);
+    let diamond = Transform::new(Vec2::zero(), FRAC_PI_4, 0.5);
+
+    test_collides(
+        &gram,
+        diamond,
+        &circ,
+        Vec2::new(1.0, 0.0),
+        0.5 - (1.0 - f64::sqrt(0.5)),
+        Vec2::new(-1.0, 0.0),
+    );
+
+    test_does_not_collide(&gram, diamond, &circ, Vec2::new(0.9, 0.9));
+}
+
+#[test]
+fn test_rotated_contains_point() {
+    let aabb = AABB::new(4.0, 2.0);
+    let upright = Transform::new(Vec2::new(1.0, 1.0), FRAC_PI_2, 1.0);
+
+    assert!(aabb.contains_point(upright, Vec2::new(1.0, 2.5)));
+    assert!(!aabb.contains_point(upright, Vec2::new(2.5, 1.0)));
+
+    let tri = Triangle::new(&[
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 1.0),
+    ]);
+    let flipped = Transform::new(Vec2::zero(), 2.0 * FRAC_PI_2, 2.0);
+
+    assert!(tri.contains_point(flipped, Vec2::new(-0.5, -0.5)));
+    assert!(!tri.contains_point(flipped, Vec2::new(0.5, 0.5)));
+    assert!(!tri.contains_point(flipped, Vec2::new(-1.5, -1.5)));
+}
+
+#[test]
+fn test_rotated_bounding_box() {
+    let bounds =
+        AABB::new(2.0, 2.0).bounding_box(Transform::new(Vec2::new(1.0, 0.0), FRAC_PI_4, 1.0));
+
+    assert_float_eq!(bounds.min.x, 1.0 - f64::sqrt(2.0), abs <= 0.0001);
+    assert_float_eq!(bounds.max.x, 1.0 + f64::sqrt(2.0), abs <= 0.0001);
+    assert_float_eq!(bounds.min.y, -f64::sqrt(2.0), abs <= 0.0001);
+    assert_float_eq!(bounds.max.y, f64::sqrt(2.0), abs <= 0.0001);
+}